@@ -0,0 +1,208 @@
+// Template destination:
+// migrations/<timestamp>_expand_example_column/up.sql
+// src/infra/db/backfill.rs
+// migrations/<timestamp>_contract_example_column/up.sql
+//
+// Zero-downtime column change in three deploys:
+// 1. Expand: add the new column as nullable (or with a constant default) and ship code that
+//    writes both old and new columns.
+// 2. Backfill: run the batched backfill job until `backfill_runs.completed_at` is set.
+// 3. Contract: ship code that reads only the new column, then run the guarded contract migration.
+//
+// Replace example_entities, column_text, column_slug, and the backfill name with project-specific
+// names. Keep the backfill in infra; it is an operational job, not domain behavior.
+
+// migrations/<timestamp>_expand_example_column/up.sql
+//
+// ALTER TABLE example_entities ADD COLUMN column_slug TEXT;
+//
+// -- One definition of the slug, used by the backfill and by dual-writing code.
+// CREATE FUNCTION example_slug(value TEXT) RETURNS TEXT
+//     LANGUAGE SQL IMMUTABLE
+//     AS $$ SELECT replace(lower(btrim(value)), ' ', '-') $$;
+//
+// CREATE TABLE IF NOT EXISTS backfill_runs (
+//     name TEXT PRIMARY KEY,
+//     last_id UUID,
+//     rows_done BIGINT NOT NULL DEFAULT 0,
+//     started_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+//     completed_at TIMESTAMPTZ
+// );
+//
+// Adding a column with a volatile default (for example `now()`) rewrites the table on older
+// Postgres versions. Prefer nullable columns or constant defaults in the expand step.
+
+// src/infra/db/backfill.rs
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use diesel::prelude::*;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::domain::repositories::RepoError;
+use crate::infra::db::postgres_connection::PgPool;
+use crate::infra::db::repositories::error_mapping::{map_diesel_error, map_pool_error};
+use crate::infra::db::schema::{backfill_runs, example_entities};
+
+diesel::define_sql_function! {
+    // Created by the expand migration.
+    fn example_slug(value: diesel::sql_types::Text) -> diesel::sql_types::Text;
+}
+
+const BACKFILL_NAME: &str = "example_entities.column_slug";
+
+pub struct BackfillConfig {
+    pub batch_size: i64,
+    pub pause_between_batches: Duration,
+}
+
+pub struct ExampleSlugBackfill {
+    pool: Arc<PgPool>,
+    config: BackfillConfig,
+}
+
+impl ExampleSlugBackfill {
+    pub fn new(pool: Arc<PgPool>, config: BackfillConfig) -> Self {
+        Self { pool, config }
+    }
+
+    // Resumable: progress is stored after every batch, so a restarted job continues from
+    // `last_id` instead of rescanning the table.
+    pub async fn run(&self) -> Result<u64, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        diesel::insert_into(backfill_runs::table)
+            .values(backfill_runs::name.eq(BACKFILL_NAME))
+            .on_conflict_do_nothing()
+            .execute(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("backfill.start", err))?;
+
+        let mut last_id: Option<Uuid> = backfill_runs::table
+            .find(BACKFILL_NAME)
+            .select(backfill_runs::last_id)
+            .first(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("backfill.load_progress", err))?;
+
+        let mut total: u64 = 0;
+
+        loop {
+            let ids = self.load_batch(&mut conn, last_id).await?;
+            let Some(&batch_last_id) = ids.last() else {
+                break;
+            };
+
+            let filled = self.fill_batch(&mut conn, &ids).await?;
+            total += filled as u64;
+            last_id = Some(batch_last_id);
+
+            diesel::update(backfill_runs::table.find(BACKFILL_NAME))
+                .set((
+                    backfill_runs::last_id.eq(last_id),
+                    backfill_runs::rows_done.eq(backfill_runs::rows_done + filled as i64),
+                ))
+                .execute(&mut conn)
+                .await
+                .map_err(|err| map_diesel_error("backfill.save_progress", err))?;
+
+            info!(backfill = BACKFILL_NAME, rows = total, "backfill batch completed");
+            tokio::time::sleep(self.config.pause_between_batches).await;
+        }
+
+        // IDs are not insert-ordered, and instances still on the old code keep inserting rows
+        // without a slug, so rows can land behind the cursor. Sweep them without a cursor; each
+        // batch fills what it loads, so this ends once no NULL slug is left.
+        loop {
+            let ids = self.load_batch(&mut conn, None).await?;
+            if ids.is_empty() {
+                break;
+            }
+
+            total += self.fill_batch(&mut conn, &ids).await? as u64;
+            info!(backfill = BACKFILL_NAME, rows = total, "backfill sweep batch completed");
+            tokio::time::sleep(self.config.pause_between_batches).await;
+        }
+
+        diesel::update(backfill_runs::table.find(BACKFILL_NAME))
+            .set(backfill_runs::completed_at.eq(Some(Utc::now())))
+            .execute(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("backfill.complete", err))?;
+
+        Ok(total)
+    }
+
+    async fn load_batch(
+        &self,
+        conn: &mut AsyncPgConnection,
+        after: Option<Uuid>,
+    ) -> Result<Vec<Uuid>, RepoError> {
+        let mut query = example_entities::table
+            .filter(example_entities::column_slug.is_null())
+            .select(example_entities::id)
+            .order(example_entities::id.asc())
+            .limit(self.config.batch_size)
+            .into_boxed();
+
+        if let Some(after) = after {
+            query = query.filter(example_entities::id.gt(after));
+        }
+
+        query
+            .load(conn)
+            .await
+            .map_err(|err| map_diesel_error("backfill.load_batch", err))
+    }
+
+    // One set-based UPDATE per batch. The NULL filter leaves rows that a dual-write filled
+    // since the batch was loaded.
+    async fn fill_batch(
+        &self,
+        conn: &mut AsyncPgConnection,
+        ids: &[Uuid],
+    ) -> Result<usize, RepoError> {
+        diesel::update(
+            example_entities::table
+                .filter(example_entities::id.eq_any(ids))
+                .filter(example_entities::column_slug.is_null()),
+        )
+        .set(example_entities::column_slug.eq(example_slug(example_entities::column_text)))
+        .execute(conn)
+        .await
+        .map_err(|err| map_diesel_error("backfill.fill_batch", err))
+    }
+}
+
+// Dual-writing code in the expand deploy sets the slug with the same function, so app writes
+// and the backfill cannot disagree:
+//
+// .set((
+//     example_entities::column_text.eq(column_text),
+//     example_entities::column_slug.eq(example_slug(column_text)),
+// ))
+
+// migrations/<timestamp>_contract_example_column/up.sql
+//
+// The guard makes the contract migration fail, and the deploy stop, until the backfill
+// job has recorded completion and no row is still missing a slug.
+//
+// DO $$
+// BEGIN
+//     IF NOT EXISTS (
+//         SELECT 1 FROM backfill_runs
+//         WHERE name = 'example_entities.column_slug' AND completed_at IS NOT NULL
+//     ) THEN
+//         RAISE EXCEPTION 'backfill example_entities.column_slug has not completed';
+//     END IF;
+//
+//     IF EXISTS (SELECT 1 FROM example_entities WHERE column_slug IS NULL) THEN
+//         RAISE EXCEPTION 'example_entities.column_slug still has NULL rows; rerun the backfill';
+//     END IF;
+// END $$;
+//
+// ALTER TABLE example_entities ALTER COLUMN column_slug SET NOT NULL;
+// ALTER TABLE example_entities DROP COLUMN legacy_column;
@@ -0,0 +1,101 @@
+// Template destination:
+// src/infra/db/schema_compat.rs
+// src/main.rs (flag handling only)
+//
+// Every migration records its compatibility in `schema_compat`:
+//
+// CREATE TABLE IF NOT EXISTS schema_compat (
+//     version TEXT PRIMARY KEY,
+//     min_app_version TEXT NOT NULL
+// );
+//
+// INSERT INTO schema_compat (version, min_app_version)
+// VALUES ('20260301000000', '20260201000000');
+//
+// `min_app_version` is the oldest binary (identified by the newest migration in its tree) that
+// still runs against the schema after this migration. Backward-compatible migrations keep it at
+// the previous release; contract migrations raise it.
+
+use std::sync::Arc;
+
+use diesel::dsl::max;
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use thiserror::Error;
+
+use crate::infra::db::postgres_connection::PgPool;
+use crate::infra::db::schema::schema_compat;
+
+// Newest migration shipped with this binary.
+pub const APP_SCHEMA_VERSION: &str = "20260301000000";
+// Oldest schema this binary can run against: the previous release's newest migration.
+pub const MIN_SCHEMA_VERSION: &str = "20260201000000";
+
+#[derive(Debug, Error)]
+pub enum SchemaCompatError {
+    #[error("database schema {db_version} is older than the minimum {MIN_SCHEMA_VERSION}")]
+    SchemaTooOld { db_version: String },
+
+    #[error("migration {version} requires app version {min_app_version} or newer")]
+    AppTooOld {
+        version: String,
+        min_app_version: String,
+    },
+
+    #[error("schema compatibility query failed")]
+    Db(#[source] anyhow::Error),
+}
+
+// Version strings are fixed-width timestamps, so string order matches version order.
+pub async fn check_schema_compat(pool: Arc<PgPool>) -> Result<(), SchemaCompatError> {
+    let mut conn = pool
+        .get()
+        .await
+        .map_err(|err| SchemaCompatError::Db(anyhow::Error::new(err)))?;
+
+    let db_version: Option<String> = schema_compat::table
+        .select(max(schema_compat::version))
+        .first(&mut conn)
+        .await
+        .map_err(|err| SchemaCompatError::Db(anyhow::Error::new(err)))?;
+
+    let db_version = db_version.unwrap_or_default();
+
+    if db_version.as_str() < MIN_SCHEMA_VERSION {
+        return Err(SchemaCompatError::SchemaTooOld { db_version });
+    }
+
+    let breaking: Option<(String, String)> = schema_compat::table
+        .filter(schema_compat::version.gt(APP_SCHEMA_VERSION))
+        .filter(schema_compat::min_app_version.gt(APP_SCHEMA_VERSION))
+        .select((schema_compat::version, schema_compat::min_app_version))
+        .order(schema_compat::version.asc())
+        .first(&mut conn)
+        .await
+        .optional()
+        .map_err(|err| SchemaCompatError::Db(anyhow::Error::new(err)))?;
+
+    if let Some((version, min_app_version)) = breaking {
+        return Err(SchemaCompatError::AppTooOld {
+            version,
+            min_app_version,
+        });
+    }
+
+    Ok(())
+}
+
+// src/main.rs
+//
+// if std::env::args().any(|arg| arg == "--check-schema-compat") {
+//     match check_schema_compat(Arc::clone(&db_pool)).await {
+//         Ok(()) => {
+//             info!(app_schema_version = APP_SCHEMA_VERSION, "schema compatible");
+//             return Ok(());
+//         }
+//         Err(err) => {
+//             error!(error = %err, "schema incompatible");
+//             std::process::exit(1);
+//         }
+//     }
+// }
@@ -0,0 +1,70 @@
+// Template: injectable clock. The trait is a domain port; usecases hold
+// `Arc<dyn Clock>` and pass `self.clock.now()` into entity constructors and
+// transitions. Entities take `now: DateTime<Utc>` and never read the system clock.
+
+// src/domain/services/clock.rs
+use chrono::{DateTime, Utc};
+
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+// src/infra/services/clock.rs
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::services::Clock;
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+// Test clock: always returns the same instant.
+pub struct FixedClock(DateTime<Utc>);
+
+impl FixedClock {
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self(now)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+// Test clock: returns `start`, then advances by `step` on every call. `advance()`
+// jumps forward explicitly, for expiry and reset-window tests.
+pub struct SteppingClock {
+    current: Mutex<DateTime<Utc>>,
+    step: Duration,
+}
+
+impl SteppingClock {
+    pub fn new(start: DateTime<Utc>, step: Duration) -> Self {
+        Self {
+            current: Mutex::new(start),
+            step,
+        }
+    }
+
+    pub fn advance(&self, by: Duration) {
+        let mut current = self.current.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        *current += by;
+    }
+}
+
+impl Clock for SteppingClock {
+    fn now(&self) -> DateTime<Utc> {
+        let mut current = self.current.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let now = *current;
+        *current += self.step;
+        now
+    }
+}
@@ -0,0 +1,214 @@
+// Template: read-replica routing. `DbPools` holds the primary pool and any
+// replica pools. Writes, transactions, and read-your-write lookups use the
+// primary. Repository read methods that tolerate replication lag opt into a
+// replica. Replicas take turns in round-robin; one that fails to hand out a
+// connection or lags too far is skipped until the health check passes again.
+// With no healthy replica, reads fall back to the primary.
+//
+// Which reads may opt in: lists, counts, pages, and exports shown to a user who
+// did not just write. Keep `find_by_id` and anything loaded before an update on
+// the primary, or a user can read the row they just changed and see old data.
+//
+// Configure with `DATABASE_URL` plus a comma-separated `DATABASE_REPLICA_URLS`.
+// An empty replica list gives the single-pool behavior.
+
+// src/infra/db/db_pools.rs
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use diesel::sql_types::Double;
+use diesel::QueryableByName;
+use diesel_async::RunQueryDsl;
+use tracing::warn;
+
+use crate::domain::repositories::RepoError;
+use crate::infra::db::connection_source::PgPooledConnection;
+use crate::infra::db::postgres_connection::PgPool;
+use crate::infra::db::repositories::error_mapping::{map_diesel_error, map_pool_error};
+
+struct Replica {
+    pool: Arc<PgPool>,
+    healthy: AtomicBool,
+}
+
+pub struct DbPools {
+    primary: Arc<PgPool>,
+    replicas: Vec<Replica>,
+    next: AtomicUsize,
+}
+
+#[derive(QueryableByName)]
+struct ReplicationLag {
+    #[diesel(sql_type = Double)]
+    lag_seconds: f64,
+}
+
+impl DbPools {
+    pub fn new(primary: Arc<PgPool>, replicas: Vec<Arc<PgPool>>) -> Self {
+        Self {
+            primary,
+            replicas: replicas
+                .into_iter()
+                .map(|pool| Replica {
+                    pool,
+                    healthy: AtomicBool::new(true),
+                })
+                .collect(),
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn primary(&self) -> &Arc<PgPool> {
+        &self.primary
+    }
+
+    pub(crate) async fn primary_conn(&self) -> Result<PgPooledConnection, RepoError> {
+        self.primary.get().await.map_err(map_pool_error)
+    }
+
+    pub(crate) async fn replica_conn(&self) -> Result<PgPooledConnection, RepoError> {
+        let count = self.replicas.len();
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+
+        for offset in 0..count {
+            let replica = &self.replicas[(start + offset) % count];
+            if !replica.healthy.load(Ordering::Relaxed) {
+                continue;
+            }
+
+            match replica.pool.get().await {
+                Ok(conn) => return Ok(conn),
+                Err(err) => {
+                    // The next health check decides when it comes back.
+                    replica.healthy.store(false, Ordering::Relaxed);
+                    warn!(error = %err, "replica unavailable; trying the next one");
+                }
+            }
+        }
+
+        self.primary_conn().await
+    }
+
+    // Marks each replica healthy when it answers and lags less than `max_lag`.
+    pub async fn check_replicas(&self, max_lag: Duration) {
+        for (index, replica) in self.replicas.iter().enumerate() {
+            let healthy = match Self::replication_lag(&replica.pool).await {
+                Ok(lag) if lag <= max_lag.as_secs_f64() => true,
+                Ok(lag) => {
+                    warn!(replica = index, lag_seconds = lag, "replica lag too high");
+                    false
+                }
+                Err(err) => {
+                    warn!(replica = index, error = %err, "replica health check failed");
+                    false
+                }
+            };
+            replica.healthy.store(healthy, Ordering::Relaxed);
+        }
+    }
+
+    async fn replication_lag(pool: &PgPool) -> Result<f64, RepoError> {
+        let mut conn = pool.get().await.map_err(map_pool_error)?;
+
+        // An idle primary sends no new WAL, so the last replay time ages even
+        // on a caught-up replica; matching LSNs mean there is nothing to replay.
+        let row = diesel::sql_query(
+            "SELECT CASE \
+                 WHEN pg_last_wal_receive_lsn() = pg_last_wal_replay_lsn() THEN 0 \
+                 ELSE COALESCE(EXTRACT(EPOCH FROM now() - pg_last_xact_replay_timestamp()), 0) \
+             END::float8 AS lag_seconds",
+        )
+        .get_result::<ReplicationLag>(&mut conn)
+        .await
+        .map_err(|err| map_diesel_error("db_pools.replication_lag", err))?;
+
+        Ok(row.lag_seconds)
+    }
+}
+
+// src/infra/db/connection_source.rs (additions to the unit_of_work.rs version)
+// `Pools` replaces `Pool` once replicas exist. `get` always uses the primary;
+// read methods that tolerate lag call `get_for_read`. Inside a transaction
+// both return the transaction's connection, so transactional reads stay on
+// the primary.
+//
+// pub(crate) enum ConnectionSource {
+//     Pools(Arc<DbPools>),
+//     Transaction(Arc<Mutex<PgPooledConnection>>),
+// }
+use crate::infra::db::db_pools::DbPools;
+
+impl ConnectionSource {
+    pub(crate) async fn get(&self) -> Result<ConnectionGuard, RepoError> {
+        match self {
+            Self::Pools(pools) => pools.primary_conn().await.map(ConnectionGuard::Pooled),
+            Self::Transaction(conn) => Ok(ConnectionGuard::Transaction(
+                Arc::clone(conn).lock_owned().await,
+            )),
+        }
+    }
+
+    pub(crate) async fn get_for_read(&self) -> Result<ConnectionGuard, RepoError> {
+        match self {
+            Self::Pools(pools) => pools.replica_conn().await.map(ConnectionGuard::Pooled),
+            Self::Transaction(_) => self.get().await,
+        }
+    }
+}
+
+// src/infra/db/repositories/example_postgres.rs
+// `new` takes `Arc<DbPools>`. Opt a read method into replicas by changing its
+// first line; everything else stays the same:
+//
+// async fn find_by_owner_page(...) -> Result<PageResponse<ExampleEntity>, RepoError> {
+//     let mut conn = self.source.get_for_read().await?;
+//     ...
+// }
+//
+// `find_by_id`, every write, and `stream_by_owner` used by exports that must
+// include just-written rows keep `self.source.get().await?`.
+
+// src/handlers/app/replica_health.rs
+// Same spawner shape as background_job.rs; no usecase, because replica health
+// is an infra concern with no business rule.
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+pub fn spawn_replica_health_check(
+    pools: Arc<DbPools>,
+    cancel: CancellationToken,
+    interval: Duration,
+    max_lag: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        info!("replica health check started");
+
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => {
+                    info!("replica health check shutting down");
+                    break;
+                }
+                _ = ticker.tick() => pools.check_replicas(max_lag).await,
+            }
+        }
+    })
+}
+
+// src/handlers/app/server.rs (startup)
+//
+// let replicas = config
+//     .database_replica_urls
+//     .iter()
+//     .map(|url| build_pool(url, config.database_pool_size).map(Arc::new))
+//     .collect::<Result<Vec<_>, _>>()?;
+// let db_pools = Arc::new(DbPools::new(Arc::new(primary), replicas));
+// spawn_replica_health_check(
+//     Arc::clone(&db_pools),
+//     cancel.clone(),
+//     Duration::from_secs(5),
+//     Duration::from_secs(2),
+// );
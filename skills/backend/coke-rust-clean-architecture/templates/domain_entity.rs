@@ -1,11 +1,14 @@
 // Template: replace ExampleEntity, ExampleEntityId, ExampleEntityName,
 // ExampleEntityStatus, and field names with project-specific names.
 // Keep this file in the domain layer. Do not import Axum, Diesel, schema,
-// handler DTOs, or infra types here.
+// handler DTOs, or infra types here. Time comes in as `now` from the usecase's
+// Clock so entities never read the system clock. `version` is the optimistic
+// locking token; the repository checks and increments it on every update.
 
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+use crate::domain::events::DomainEvent;
 use crate::domain::value_objects::{ExampleEntityId, ExampleEntityName, ExampleEntityStatus};
 use crate::domain::DomainError;
 
@@ -19,14 +22,21 @@ pub struct ExampleEntity {
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
     deleted_at: Option<DateTime<Utc>>,
+    version: i32,
+    events: Vec<DomainEvent>,
 }
 
 impl ExampleEntity {
-    pub fn new(owner_id: Uuid, column_text: ExampleEntityName, column_url: String) -> Self {
-        let now = Utc::now();
+    pub fn new(
+        owner_id: Uuid,
+        column_text: ExampleEntityName,
+        column_url: String,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let id = ExampleEntityId::new();
 
         Self {
-            id: ExampleEntityId::new(),
+            id,
             owner_id,
             column_text,
             column_url,
@@ -34,6 +44,12 @@ impl ExampleEntity {
             created_at: now,
             updated_at: now,
             deleted_at: None,
+            version: 1,
+            events: vec![DomainEvent::ExampleEntityCreated {
+                id,
+                owner_id,
+                occurred_at: now,
+            }],
         }
     }
 
@@ -47,6 +63,7 @@ impl ExampleEntity {
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
         deleted_at: Option<DateTime<Utc>>,
+        version: i32,
     ) -> Self {
         Self {
             id,
@@ -57,6 +74,8 @@ impl ExampleEntity {
             created_at,
             updated_at,
             deleted_at,
+            version,
+            events: Vec::new(),
         }
     }
 
@@ -92,33 +111,59 @@ impl ExampleEntity {
         self.deleted_at
     }
 
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
     pub fn is_deleted(&self) -> bool {
         self.deleted_at.is_some()
     }
 
-    pub fn rename(&mut self, column_text: ExampleEntityName) -> Result<(), DomainError> {
+    pub fn rename(
+        &mut self,
+        column_text: ExampleEntityName,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
         self.ensure_not_deleted()?;
-        self.column_text = column_text;
-        self.updated_at = Utc::now();
+        self.column_text = column_text.clone();
+        self.updated_at = now;
+        self.events.push(DomainEvent::ExampleEntityRenamed {
+            id: self.id,
+            column_text,
+            occurred_at: now,
+        });
         Ok(())
     }
 
-    pub fn change_status(&mut self, status: ExampleEntityStatus) -> Result<(), DomainError> {
+    pub fn change_status(
+        &mut self,
+        status: ExampleEntityStatus,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
         self.ensure_not_deleted()?;
         self.status = status;
-        self.updated_at = Utc::now();
+        self.updated_at = now;
         Ok(())
     }
 
-    pub fn soft_delete(&mut self) -> Result<(), DomainError> {
+    pub fn soft_delete(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
         self.ensure_not_deleted()?;
-        let now = Utc::now();
         self.status = ExampleEntityStatus::Inactive;
         self.updated_at = now;
         self.deleted_at = Some(now);
+        self.events.push(DomainEvent::ExampleEntityDeleted {
+            id: self.id,
+            occurred_at: now,
+        });
         Ok(())
     }
 
+    // Usecases drain events after a successful write and hand them to the
+    // dispatcher or outbox. Entities never publish events themselves.
+    pub fn take_events(&mut self) -> Vec<DomainEvent> {
+        std::mem::take(&mut self.events)
+    }
+
     fn ensure_not_deleted(&self) -> Result<(), DomainError> {
         if self.is_deleted() {
             return Err(DomainError::InvariantViolation(
@@ -129,3 +174,66 @@ impl ExampleEntity {
         Ok(())
     }
 }
+
+// Optional partial-update diff. Keep only when a repository needs `update_changed`
+// to write modified columns instead of rewriting every column on hot rows.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExampleEntityChanges {
+    column_text: Option<ExampleEntityName>,
+    column_url: Option<String>,
+    status: Option<ExampleEntityStatus>,
+    deleted_at: Option<Option<DateTime<Utc>>>,
+    updated_at: Option<DateTime<Utc>>,
+    expected_version: i32,
+}
+
+impl ExampleEntityChanges {
+    pub fn between(before: &ExampleEntity, after: &ExampleEntity) -> Self {
+        let mut changes = Self {
+            column_text: (before.column_text != after.column_text)
+                .then(|| after.column_text.clone()),
+            column_url: (before.column_url != after.column_url).then(|| after.column_url.clone()),
+            status: (before.status != after.status).then_some(after.status),
+            deleted_at: (before.deleted_at != after.deleted_at).then_some(after.deleted_at),
+            updated_at: None,
+            expected_version: before.version,
+        };
+
+        if !changes.is_empty() {
+            changes.updated_at = Some(after.updated_at);
+        }
+
+        changes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.column_text.is_none()
+            && self.column_url.is_none()
+            && self.status.is_none()
+            && self.deleted_at.is_none()
+    }
+
+    pub fn column_text(&self) -> Option<&ExampleEntityName> {
+        self.column_text.as_ref()
+    }
+
+    pub fn column_url(&self) -> Option<&str> {
+        self.column_url.as_deref()
+    }
+
+    pub fn status(&self) -> Option<ExampleEntityStatus> {
+        self.status
+    }
+
+    pub fn deleted_at(&self) -> Option<Option<DateTime<Utc>>> {
+        self.deleted_at
+    }
+
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+
+    pub fn expected_version(&self) -> i32 {
+        self.expected_version
+    }
+}
@@ -0,0 +1,62 @@
+// Template: typed domain events recorded by entity transitions. Events carry IDs
+// and the changed values, not whole entities, so they stay cheap to clone and
+// safe to serialize into the outbox (outbox.rs).
+
+// src/domain/events/domain_event.rs
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::value_objects::{ExampleEntityId, ExampleEntityName};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DomainEvent {
+    ExampleEntityCreated {
+        id: ExampleEntityId,
+        owner_id: Uuid,
+        occurred_at: DateTime<Utc>,
+    },
+    ExampleEntityRenamed {
+        id: ExampleEntityId,
+        column_text: ExampleEntityName,
+        occurred_at: DateTime<Utc>,
+    },
+    ExampleEntityDeleted {
+        id: ExampleEntityId,
+        occurred_at: DateTime<Utc>,
+    },
+}
+
+impl DomainEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ExampleEntityCreated { .. } => "example_entity.created",
+            Self::ExampleEntityRenamed { .. } => "example_entity.renamed",
+            Self::ExampleEntityDeleted { .. } => "example_entity.deleted",
+        }
+    }
+
+    pub fn aggregate_id(&self) -> Uuid {
+        match self {
+            Self::ExampleEntityCreated { id, .. }
+            | Self::ExampleEntityRenamed { id, .. }
+            | Self::ExampleEntityDeleted { id, .. } => id.into_uuid(),
+        }
+    }
+
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        match self {
+            Self::ExampleEntityCreated { occurred_at, .. }
+            | Self::ExampleEntityRenamed { occurred_at, .. }
+            | Self::ExampleEntityDeleted { occurred_at, .. } => *occurred_at,
+        }
+    }
+}
+
+// src/usecases/example_feature/rename.rs
+//
+// entity.rename(column_text, self.clock.now())?;
+// self.example_repo.update(&entity).await?;
+//
+// self.dispatcher.dispatch(entity.take_events()).await;
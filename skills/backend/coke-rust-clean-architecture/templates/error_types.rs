@@ -1,16 +1,20 @@
 // Template: split these sections into the indicated layer files. Keep the
 // conversion flow DomainError -> UsecaseError -> ApiError and
-// RepoError -> UsecaseError -> ApiError.
+// RepoError -> UsecaseError -> ApiError. `InvalidField` carries a message key
+// rather than English text; see message_renderer.rs for rendering.
 
 // src/domain/error.rs
+use std::borrow::Cow;
+
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum DomainError {
-    #[error("Invalid field '{field}': {reason}")]
+    #[error("Invalid field '{field}': {key}")]
     InvalidField {
-        field: &'static str,
-        reason: &'static str,
+        field: Cow<'static, str>,
+        key: &'static str,
+        params: Vec<(&'static str, String)>,
     },
 
     #[error("Invariant violation: {0}")]
@@ -23,6 +27,26 @@ pub enum DomainError {
     Conflict(String),
 }
 
+impl DomainError {
+    // Accepts a literal (`"email"`) or a runtime path (`format!("headers.{name}")`).
+    // `key` is a message id from locales/*/validation.ftl, never English text.
+    pub fn invalid_field(field: impl Into<Cow<'static, str>>, key: &'static str) -> Self {
+        Self::InvalidField {
+            field: field.into(),
+            key,
+            params: Vec::new(),
+        }
+    }
+
+    // Adds a message argument such as `max`; other variants are returned unchanged.
+    pub fn with_param(mut self, name: &'static str, value: impl ToString) -> Self {
+        if let Self::InvalidField { params, .. } = &mut self {
+            params.push((name, value.to_string()));
+        }
+        self
+    }
+}
+
 // src/domain/repositories/error.rs
 use thiserror::Error;
 
@@ -44,8 +68,59 @@ pub enum RepoError {
     #[error("Foreign key violation: {0}")]
     ForeignKeyViolation(String),
 
+    #[error("Stale version: {0}")]
+    StaleVersion(String),
+
+    // Chunked batch writes: `chunk` is zero-based; the whole batch rolled back.
+    #[error("Database operation '{op}' failed at chunk {chunk}")]
+    ChunkFailed {
+        op: &'static str,
+        chunk: usize,
+        #[source]
+        source: Box<RepoError>,
+    },
+
     #[error("Connection error: {0}")]
     ConnectionError(String),
+
+    // The database rolled the statement back (serialization failure, deadlock);
+    // running it again may succeed.
+    #[error("Database operation '{op}' failed transiently")]
+    Transient {
+        op: &'static str,
+        #[source]
+        source: anyhow::Error,
+    },
+
+    // A serializable transaction kept failing with serialization conflicts.
+    #[error("Transaction '{op}' conflicted after {attempts} attempts")]
+    TxConflict {
+        op: &'static str,
+        attempts: u32,
+    },
+}
+
+impl RepoError {
+    // True when nothing was written and the same call may succeed on retry.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::ConnectionError(_) | Self::Transient { .. } => true,
+            Self::ChunkFailed { source, .. } => source.is_transient(),
+            _ => false,
+        }
+    }
+}
+
+// src/domain/services/error.rs
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ServiceError {
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+
+    #[error("Service unavailable: {0}")]
+    Unavailable(String),
 }
 
 // src/infra/db/repositories/error_mapping.rs
@@ -62,6 +137,24 @@ pub(crate) fn map_diesel_error(op: &'static str, err: DieselError) -> RepoError
         DieselError::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, info) => {
             RepoError::ForeignKeyViolation(info.message().to_string())
         }
+        DieselError::DatabaseError(DatabaseErrorKind::SerializationFailure, _) => {
+            RepoError::Transient {
+                op,
+                source: anyhow::Error::new(err),
+            }
+        }
+        // Diesel has no kind for SQLSTATE 40P01 and does not expose the code, so
+        // this matches the server message. That message is translated unless the
+        // database runs with English messages; the migration in
+        // references/error-handling.md pins `lc_messages` for that reason.
+        DieselError::DatabaseError(DatabaseErrorKind::Unknown, info)
+            if info.message().starts_with("deadlock detected") =>
+        {
+            RepoError::Transient {
+                op,
+                source: anyhow::Error::new(err),
+            }
+        }
         _ => RepoError::Db {
             op,
             source: anyhow::Error::new(err),
@@ -73,8 +166,42 @@ pub(crate) fn map_pool_error(err: impl std::error::Error + Send + Sync + 'static
     RepoError::ConnectionError(err.to_string())
 }
 
+// Carries the failing chunk out of a `create_many` transaction closure, which
+// can only return an error type built from DieselError. Shared by the Postgres
+// and SQLite repositories.
+pub(crate) struct ChunkInsertError {
+    pub(crate) chunk: Option<usize>,
+    pub(crate) source: DieselError,
+}
+
+impl From<DieselError> for ChunkInsertError {
+    fn from(source: DieselError) -> Self {
+        Self {
+            chunk: None,
+            source,
+        }
+    }
+}
+
+impl ChunkInsertError {
+    pub(crate) fn into_repo_error(self, op: &'static str) -> RepoError {
+        let err = map_diesel_error(op, self.source);
+        match self.chunk {
+            Some(chunk) => RepoError::ChunkFailed {
+                op,
+                chunk,
+                source: Box::new(err),
+            },
+            None => err,
+        }
+    }
+}
+
 // src/usecases/error.rs
+use std::borrow::Cow;
+
 use crate::domain::repositories::RepoError;
+use crate::domain::services::ServiceError;
 use crate::domain::DomainError;
 use thiserror::Error;
 
@@ -86,6 +213,13 @@ pub enum UsecaseError {
     #[error("Validation error: {0}")]
     Validation(String),
 
+    #[error("Invalid field '{field}': {key}")]
+    InvalidField {
+        field: Cow<'static, str>,
+        key: &'static str,
+        params: Vec<(&'static str, String)>,
+    },
+
     #[error("Conflict: {0}")]
     Conflict(String),
 
@@ -98,9 +232,10 @@ impl From<DomainError> for UsecaseError {
         match err {
             DomainError::NotFound(message) => Self::NotFound(message),
             DomainError::Conflict(message) => Self::Conflict(message),
-            DomainError::InvalidField { .. } | DomainError::InvariantViolation(_) => {
-                Self::Validation(err.to_string())
+            DomainError::InvalidField { field, key, params } => {
+                Self::InvalidField { field, key, params }
             }
+            DomainError::InvariantViolation(_) => Self::Validation(err.to_string()),
         }
     }
 }
@@ -109,15 +244,31 @@ impl From<RepoError> for UsecaseError {
     fn from(err: RepoError) -> Self {
         match err {
             RepoError::NotFound(message) => Self::NotFound(message),
-            RepoError::UniqueViolation(message) | RepoError::ForeignKeyViolation(message) => {
-                Self::Conflict(message)
-            }
+            RepoError::UniqueViolation(message)
+            | RepoError::ForeignKeyViolation(message)
+            | RepoError::StaleVersion(message) => Self::Conflict(message),
+            err @ RepoError::TxConflict { .. } => Self::Conflict(err.to_string()),
+            RepoError::ChunkFailed { chunk, source, .. } => match Self::from(*source) {
+                Self::Conflict(message) => Self::Conflict(format!("chunk {chunk}: {message}")),
+                other => other,
+            },
+            other => Self::Infra(anyhow::Error::new(other)),
+        }
+    }
+}
+
+impl From<ServiceError> for UsecaseError {
+    fn from(err: ServiceError) -> Self {
+        match err {
+            ServiceError::InvalidRequest(message) => Self::Validation(message),
             other => Self::Infra(anyhow::Error::new(other)),
         }
     }
 }
 
 // src/handlers/shared/error.rs
+use std::borrow::Cow;
+
 use axum::{
     http::StatusCode,
     response::{IntoResponse, Json},
@@ -141,6 +292,15 @@ struct ErrorBody {
     message: String,
 }
 
+// Attached to 400 responses for `InvalidField`; `localize_errors` in
+// handlers/app/middleware.rs replaces the body with the rendered message.
+#[derive(Debug, Clone)]
+pub struct ValidationFailure {
+    pub field: Cow<'static, str>,
+    pub key: &'static str,
+    pub params: Vec<(&'static str, String)>,
+}
+
 impl IntoResponse for ApiError {
     fn into_response(self) -> axum::response::Response {
         let (status, code, message) = match self.0 {
@@ -148,6 +308,17 @@ impl IntoResponse for ApiError {
             UsecaseError::Validation(message) => {
                 (StatusCode::BAD_REQUEST, "VALIDATION_ERROR", message)
             }
+            UsecaseError::InvalidField { field, key, params } => {
+                let body = ErrorBody {
+                    error: "VALIDATION_ERROR",
+                    message: key.to_string(),
+                };
+                let mut response = (StatusCode::BAD_REQUEST, Json(body)).into_response();
+                response
+                    .extensions_mut()
+                    .insert(ValidationFailure { field, key, params });
+                return response;
+            }
             UsecaseError::Conflict(message) => (StatusCode::CONFLICT, "CONFLICT", message),
             UsecaseError::Infra(err) => {
                 error!(error = ?err, "internal usecase error");
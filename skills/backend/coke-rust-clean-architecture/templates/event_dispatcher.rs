@@ -0,0 +1,151 @@
+// Template: in-process domain event dispatcher. Usecases drain events from
+// entities with `take_events()` after a successful write and call `dispatch()`.
+// Subscribers are usecase-layer side effects (send email, invalidate cache) and
+// depend on domain ports only.
+//
+// `Sync` mode awaits subscribers in registration order before the usecase
+// returns. `Async` mode runs the same ordered loop on a Tokio task so slow
+// subscribers do not add request latency. Neither mode survives a crash; use the
+// outbox when delivery must be guaranteed.
+
+// src/usecases/events/subscriber.rs
+use async_trait::async_trait;
+
+use crate::domain::events::DomainEvent;
+use crate::usecases::UsecaseError;
+
+#[async_trait]
+pub trait EventSubscriber: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn handles(&self, event: &DomainEvent) -> bool;
+
+    async fn handle(&self, event: &DomainEvent) -> Result<(), UsecaseError>;
+}
+
+// src/usecases/events/dispatcher.rs
+use std::sync::Arc;
+
+use tracing::{error, info_span, Instrument};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DispatchMode {
+    Sync,
+    Async,
+}
+
+#[derive(Clone)]
+pub struct EventDispatcher {
+    mode: DispatchMode,
+    subscribers: Arc<Vec<Arc<dyn EventSubscriber>>>,
+}
+
+impl EventDispatcher {
+    pub fn builder(mode: DispatchMode) -> EventDispatcherBuilder {
+        EventDispatcherBuilder {
+            mode,
+            subscribers: Vec::new(),
+        }
+    }
+
+    // Subscriber failures are logged and do not fail the usecase: the write that
+    // produced the event has already committed.
+    pub async fn dispatch(&self, events: Vec<DomainEvent>) {
+        if events.is_empty() {
+            return;
+        }
+
+        match self.mode {
+            DispatchMode::Sync => run_subscribers(&self.subscribers, &events).await,
+            DispatchMode::Async => {
+                let subscribers = Arc::clone(&self.subscribers);
+                tokio::spawn(async move { run_subscribers(&subscribers, &events).await });
+            }
+        }
+    }
+}
+
+async fn run_subscribers(subscribers: &[Arc<dyn EventSubscriber>], events: &[DomainEvent]) {
+    for event in events {
+        for subscriber in subscribers.iter().filter(|subscriber| subscriber.handles(event)) {
+            let span = info_span!(
+                "event_subscriber",
+                subscriber = subscriber.name(),
+                event = event.name()
+            );
+
+            if let Err(err) = subscriber.handle(event).instrument(span).await {
+                error!(
+                    subscriber = subscriber.name(),
+                    event = event.name(),
+                    error = %err,
+                    "event subscriber failed"
+                );
+            }
+        }
+    }
+}
+
+pub struct EventDispatcherBuilder {
+    mode: DispatchMode,
+    subscribers: Vec<Arc<dyn EventSubscriber>>,
+}
+
+impl EventDispatcherBuilder {
+    pub fn subscribe(mut self, subscriber: Arc<dyn EventSubscriber>) -> Self {
+        self.subscribers.push(subscriber);
+        self
+    }
+
+    pub fn build(self) -> EventDispatcher {
+        EventDispatcher {
+            mode: self.mode,
+            subscribers: Arc::new(self.subscribers),
+        }
+    }
+}
+
+// src/usecases/events/invalidate_example_cache.rs
+use crate::domain::services::ExampleCache;
+
+pub struct InvalidateExampleCacheSubscriber {
+    cache: Arc<dyn ExampleCache>,
+}
+
+impl InvalidateExampleCacheSubscriber {
+    pub fn new(cache: Arc<dyn ExampleCache>) -> Self {
+        Self { cache }
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for InvalidateExampleCacheSubscriber {
+    fn name(&self) -> &'static str {
+        "invalidate_example_cache"
+    }
+
+    fn handles(&self, event: &DomainEvent) -> bool {
+        matches!(
+            event,
+            DomainEvent::ExampleEntityRenamed { .. } | DomainEvent::ExampleEntityDeleted { .. }
+        )
+    }
+
+    async fn handle(&self, event: &DomainEvent) -> Result<(), UsecaseError> {
+        match event {
+            DomainEvent::ExampleEntityRenamed { id, .. }
+            | DomainEvent::ExampleEntityDeleted { id, .. } => {
+                self.cache.invalidate(id).await?;
+            }
+            DomainEvent::ExampleEntityCreated { .. } => {}
+        }
+
+        Ok(())
+    }
+}
+
+// src/handlers/app/state.rs (built once at startup)
+//
+// let dispatcher = EventDispatcher::builder(DispatchMode::Async)
+//     .subscribe(Arc::new(InvalidateExampleCacheSubscriber::new(Arc::clone(&cache))))
+//     .build();
@@ -53,7 +53,7 @@ pub async fn create_example_entity(
     let example_repo: Arc<dyn ExampleRepository> =
         Arc::new(ExamplePostgres::new(Arc::clone(&state.db_pool)));
 
-    let usecase = CreateExampleEntityUseCase::new(example_repo);
+    let usecase = CreateExampleEntityUseCase::new(example_repo, Arc::clone(&state.clock));
 
     let input = CreateExampleEntityInput {
         owner_id: auth.user_id,
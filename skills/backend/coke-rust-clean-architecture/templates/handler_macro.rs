@@ -0,0 +1,86 @@
+// Template: `usecase_handler!` generates the body that handler_axum.rs writes by
+// hand: extract state, auth, and JSON body; build the usecase; map request to
+// input; call it; map output to the response DTO. Use it once a project has
+// many handlers with exactly that shape.
+//
+// What stays explicit:
+// - Request and response DTOs, and `From<Output>` for the response. They are the
+//   API contract, so review them as code, not as macro arguments.
+// - Route registration in `handlers/routers/{surface}/`.
+// - Usecases. They never carry HTTP methods, paths, or status codes.
+//
+// Write the handler by hand when it needs other extractors (Path, Query), no
+// auth, headers, or a custom response.
+//
+// Out of scope: this is a declarative macro for the handler body, not a
+// `#[api_usecase(method, path)]` attribute macro. It does not register routes
+// or emit OpenAPI entries. Register the handler with `register_route!`
+// (route_registry.rs) or a feature router, and pass `#[utoipa::path(...)]`
+// through the leading attributes, which land on the generated fn.
+
+// src/handlers/shared/usecase_handler.rs
+#[macro_export]
+macro_rules! usecase_handler {
+    (
+        $(#[$meta:meta])*
+        $name:ident,
+        request: $request:ty,
+        response: $response:ty,
+        status: $status:expr,
+        usecase: |$state:ident| $usecase:expr,
+        input: |$auth:ident, $body:ident| $input:expr $(,)?
+    ) => {
+        $(#[$meta])*
+        pub async fn $name(
+            ::axum::extract::State(state): ::axum::extract::State<
+                $crate::handlers::app::state::AppState,
+            >,
+            auth: $crate::handlers::shared::auth::AuthenticatedUser,
+            ::axum::Json(body): ::axum::Json<$request>,
+        ) -> ::std::result::Result<
+            impl ::axum::response::IntoResponse,
+            $crate::handlers::shared::error::ApiError,
+        > {
+            let $state = &state;
+            let usecase = $usecase;
+
+            let $auth = auth;
+            let $body = body;
+            let input = $input;
+
+            let output = usecase.execute(input).await?;
+
+            Ok(($status, ::axum::Json(<$response>::from(output))))
+        }
+    };
+}
+
+// src/handlers/routers/public_api/example_action.rs
+// The same handler as handler_axum.rs. DTOs and `From` stay as written there.
+use std::sync::Arc;
+
+use axum::http::StatusCode;
+
+use crate::infra::db::repositories::ExamplePostgres;
+use crate::usecases::{CreateExampleEntityInput, CreateExampleEntityUseCase};
+
+crate::usecase_handler! {
+    /// Creates an example entity owned by the caller.
+    create_example_entity,
+    request: CreateExampleEntityRequest,
+    response: CreateExampleEntityResponse,
+    status: StatusCode::CREATED,
+    usecase: |state| CreateExampleEntityUseCase::new(
+        Arc::new(ExamplePostgres::new(Arc::clone(&state.db_pool))),
+        Arc::clone(&state.clock),
+    ),
+    input: |auth, body| CreateExampleEntityInput {
+        owner_id: auth.user_id,
+        column_text: body.column_text,
+        column_url: body.column_url,
+    },
+}
+
+// src/handlers/routers/public_api/example_feature.rs (unchanged)
+//
+// Router::new().route("/", post(example_action::create_example_entity))
@@ -0,0 +1,41 @@
+// Template: ID generation strategies selected per aggregate through
+// `define_id!(Name, generator = ...)`. All strategies produce a `Uuid`, so the
+// `uuid` column type, row structs, and call sites stay the same.
+//
+// Cargo features: `uuid = { features = ["v4", "v7"] }`,
+// `ulid = { features = ["uuid"] }`.
+
+// src/domain/value_objects/ids/id_generator.rs
+use uuid::Uuid;
+
+pub trait IdGenerator {
+    fn generate() -> Uuid;
+}
+
+// Random IDs. Default for aggregates without insert-order requirements.
+pub struct UuidV4Generator;
+
+impl IdGenerator for UuidV4Generator {
+    fn generate() -> Uuid {
+        Uuid::new_v4()
+    }
+}
+
+// Time-ordered RFC 9562 IDs. Index-friendly for high-insert tables.
+pub struct UuidV7Generator;
+
+impl IdGenerator for UuidV7Generator {
+    fn generate() -> Uuid {
+        Uuid::now_v7()
+    }
+}
+
+// Time-ordered ULIDs stored as UUIDs. Use when IDs are also rendered in the
+// ULID text form for external systems.
+pub struct UlidGenerator;
+
+impl IdGenerator for UlidGenerator {
+    fn generate() -> Uuid {
+        Uuid::from(ulid::Ulid::new())
+    }
+}
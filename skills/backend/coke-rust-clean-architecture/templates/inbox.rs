@@ -0,0 +1,279 @@
+// Template: consumer-side inbox. Pairs with outbox.rs: the bus delivers at least
+// once, so every consumer records the message ID before applying side effects
+// and skips IDs it has already seen. A sweeper purges processed rows once they
+// are older than the redelivery window.
+//
+// Keep the direction:
+// consumer/spawner -> usecase -> InboxRepository (domain port)
+// infra inbox repository -> domain port
+
+// migrations/{timestamp}_create_inbox_messages/up.sql
+//
+// CREATE TABLE inbox_messages (
+//     message_id UUID PRIMARY KEY,
+//     message_type TEXT NOT NULL,
+//     received_at TIMESTAMPTZ NOT NULL,
+//     processed_at TIMESTAMPTZ
+// );
+//
+// CREATE INDEX inbox_messages_processed_at_idx
+//     ON inbox_messages (processed_at)
+//     WHERE processed_at IS NOT NULL;
+
+// src/domain/repositories/inbox_repository.rs
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::repositories::RepoError;
+
+#[async_trait]
+pub trait InboxRepository: Send + Sync {
+    // Returns `false` when the message ID was already recorded. A row still
+    // unprocessed and received before `reclaim_before` belongs to a consumer
+    // that crashed mid-handling; it is claimed again and this returns `true`.
+    async fn record_if_new(
+        &self,
+        message_id: &Uuid,
+        message_type: &str,
+        received_at: DateTime<Utc>,
+        reclaim_before: DateTime<Utc>,
+    ) -> Result<bool, RepoError>;
+
+    async fn mark_processed(
+        &self,
+        message_id: &Uuid,
+        processed_at: DateTime<Utc>,
+    ) -> Result<(), RepoError>;
+
+    // Forgets a message whose handling failed so redelivery processes it again.
+    async fn release(&self, message_id: &Uuid) -> Result<(), RepoError>;
+
+    async fn purge_processed_before(&self, cutoff: DateTime<Utc>) -> Result<usize, RepoError>;
+}
+
+// src/infra/db/repositories/inbox_postgres.rs
+use std::sync::Arc;
+
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+
+use crate::infra::db::postgres_connection::PgPool;
+use crate::infra::db::schema::inbox_messages;
+
+use super::error_mapping::{map_diesel_error, map_pool_error};
+
+#[derive(Insertable)]
+#[diesel(table_name = inbox_messages)]
+struct NewInboxMessageRow<'a> {
+    message_id: &'a Uuid,
+    message_type: &'a str,
+    received_at: DateTime<Utc>,
+}
+
+pub struct InboxPostgres {
+    pool: Arc<PgPool>,
+}
+
+impl InboxPostgres {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InboxRepository for InboxPostgres {
+    async fn record_if_new(
+        &self,
+        message_id: &Uuid,
+        message_type: &str,
+        received_at: DateTime<Utc>,
+        reclaim_before: DateTime<Utc>,
+    ) -> Result<bool, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let new_row = NewInboxMessageRow {
+            message_id,
+            message_type,
+            received_at,
+        };
+
+        // The primary key arbitrates concurrent deliveries of the same message.
+        // A conflicting row is only taken over when its lease has run out.
+        let rows_affected = diesel::insert_into(inbox_messages::table)
+            .values(&new_row)
+            .on_conflict(inbox_messages::message_id)
+            .do_update()
+            .set(inbox_messages::received_at.eq(received_at))
+            .filter(
+                inbox_messages::processed_at
+                    .is_null()
+                    .and(inbox_messages::received_at.lt(reclaim_before)),
+            )
+            .execute(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("inbox.record_if_new", err))?;
+
+        Ok(rows_affected == 1)
+    }
+
+    async fn mark_processed(
+        &self,
+        message_id: &Uuid,
+        processed_at: DateTime<Utc>,
+    ) -> Result<(), RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        diesel::update(inbox_messages::table.find(message_id))
+            .set(inbox_messages::processed_at.eq(processed_at))
+            .execute(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("inbox.mark_processed", err))?;
+
+        Ok(())
+    }
+
+    async fn release(&self, message_id: &Uuid) -> Result<(), RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        diesel::delete(
+            inbox_messages::table
+                .find(message_id)
+                .filter(inbox_messages::processed_at.is_null()),
+        )
+        .execute(&mut conn)
+        .await
+        .map_err(|err| map_diesel_error("inbox.release", err))?;
+
+        Ok(())
+    }
+
+    async fn purge_processed_before(&self, cutoff: DateTime<Utc>) -> Result<usize, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        diesel::delete(inbox_messages::table.filter(inbox_messages::processed_at.lt(cutoff)))
+            .execute(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("inbox.purge_processed_before", err))
+    }
+}
+
+// src/usecases/example_feature/handle_example_event.rs
+// Consumer shape. A crash between `record_if_new` and `mark_processed` leaves
+// the row unprocessed. Redeliveries inside `lease` are skipped as in-flight;
+// the first one after it reclaims the row and processes the message again.
+use crate::domain::services::Clock;
+use crate::usecases::UsecaseError;
+
+pub struct HandleExampleEventUseCase {
+    inbox_repo: Arc<dyn InboxRepository>,
+    clock: Arc<dyn Clock>,
+    lease: chrono::Duration,
+}
+
+impl HandleExampleEventUseCase {
+    // `lease` must exceed the longest time `apply` can take, or a slow handler
+    // and a redelivery run the side effects concurrently.
+    pub fn new(
+        inbox_repo: Arc<dyn InboxRepository>,
+        clock: Arc<dyn Clock>,
+        lease: chrono::Duration,
+    ) -> Self {
+        Self {
+            inbox_repo,
+            clock,
+            lease,
+        }
+    }
+
+    pub async fn execute(&self, message_id: Uuid, message_type: &str) -> Result<(), UsecaseError> {
+        let now = self.clock.now();
+        let is_new = self
+            .inbox_repo
+            .record_if_new(&message_id, message_type, now, now - self.lease)
+            .await?;
+
+        if !is_new {
+            return Ok(());
+        }
+
+        if let Err(err) = self.apply(message_type).await {
+            self.inbox_repo.release(&message_id).await?;
+            return Err(err);
+        }
+
+        self.inbox_repo
+            .mark_processed(&message_id, self.clock.now())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn apply(&self, _message_type: &str) -> Result<(), UsecaseError> {
+        // Project-specific side effects.
+        Ok(())
+    }
+}
+
+// src/usecases/inbox/purge.rs
+pub struct PurgeInboxUseCase {
+    inbox_repo: Arc<dyn InboxRepository>,
+    clock: Arc<dyn Clock>,
+    retention: chrono::Duration,
+}
+
+impl PurgeInboxUseCase {
+    // `retention` must exceed the longest redelivery window of the bus, or a late
+    // duplicate is processed twice.
+    pub fn new(
+        inbox_repo: Arc<dyn InboxRepository>,
+        clock: Arc<dyn Clock>,
+        retention: chrono::Duration,
+    ) -> Self {
+        Self {
+            inbox_repo,
+            clock,
+            retention,
+        }
+    }
+
+    pub async fn execute(&self) -> Result<usize, UsecaseError> {
+        let cutoff = self.clock.now() - self.retention;
+        let purged = self.inbox_repo.purge_processed_before(cutoff).await?;
+
+        Ok(purged)
+    }
+}
+
+// src/handlers/app/inbox_sweeper.rs
+// Same spawner shape as background_job.rs.
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+pub fn spawn_inbox_sweeper(
+    usecase: Arc<PurgeInboxUseCase>,
+    cancel: CancellationToken,
+    interval_secs: u64,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        info!("inbox sweeper started");
+
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => {
+                    info!("inbox sweeper shutting down");
+                    break;
+                }
+                _ = interval.tick() => {
+                    match usecase.execute().await {
+                        Ok(purged) => info!(purged, "inbox sweeper purged processed messages"),
+                        Err(err) => error!(error = %err, "inbox sweeper failed"),
+                    }
+                }
+            }
+        }
+    })
+}
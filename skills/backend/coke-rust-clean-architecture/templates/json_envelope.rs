@@ -0,0 +1,326 @@
+// Template: versioned envelope for JSONB columns (preferences, rules, stored
+// templates). Rows store `{"v": 2, "data": {...}}`. Reads run registered upgrade
+// functions up to the current version, so the domain only sees the latest
+// shape; a background job rewrites old rows so upgrades can eventually be
+// deleted. The envelope is a storage detail and stays in infra.
+//
+// Changing a stored shape:
+// 1. Add `upgrade_vN_to_vN+1` and append it to `UPGRADES`.
+// 2. Bump `CURRENT_VERSION` and change the Rust struct.
+// 3. Deploy; reads upgrade on the fly and the backfill job rewrites rows.
+// 4. After the job finishes a pass that skipped no rows, and a count of rows
+//    with `json_envelope_version(column) < CURRENT_VERSION` is zero, old
+//    upgrades may be removed.
+//
+// Never edit an upgrade that has shipped; rows may still be mid-migration.
+
+// src/infra/db/json_envelope.rs
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::domain::repositories::RepoError;
+
+pub(crate) type JsonUpgrade = fn(Value) -> Result<Value, String>;
+
+pub(crate) trait VersionedJson: Serialize + DeserializeOwned {
+    const CURRENT_VERSION: u32;
+
+    // `UPGRADES[i]` turns version `i + 1` into `i + 2`, so the list has exactly
+    // `CURRENT_VERSION - 1` entries.
+    const UPGRADES: &'static [JsonUpgrade];
+}
+
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    v: u32,
+    data: Value,
+}
+
+pub(crate) struct Decoded<T> {
+    pub value: T,
+    // True when the stored row is behind and should be rewritten.
+    pub upgraded: bool,
+}
+
+pub(crate) fn encode<T: VersionedJson>(op: &'static str, value: &T) -> Result<Value, RepoError> {
+    let data = serde_json::to_value(value).map_err(|err| envelope_error(op, err.to_string()))?;
+
+    serde_json::to_value(Envelope {
+        v: T::CURRENT_VERSION,
+        data,
+    })
+    .map_err(|err| envelope_error(op, err.to_string()))
+}
+
+pub(crate) fn decode<T: VersionedJson>(
+    op: &'static str,
+    raw: Value,
+) -> Result<Decoded<T>, RepoError> {
+    // Rows written before the envelope existed are bare objects: version 1.
+    let Envelope { v, mut data } = match serde_json::from_value::<Envelope>(raw.clone()) {
+        Ok(envelope) => envelope,
+        Err(_) => Envelope { v: 1, data: raw },
+    };
+
+    // A newer deploy wrote this row; refuse to guess at a downgrade.
+    if v == 0 || v > T::CURRENT_VERSION {
+        return Err(envelope_error(op, format!("unsupported json version {v}")));
+    }
+
+    for (index, upgrade) in T::UPGRADES.iter().enumerate().skip(v as usize - 1) {
+        data = upgrade(data).map_err(|reason| {
+            envelope_error(op, format!("upgrade from v{} failed: {reason}", index + 1))
+        })?;
+    }
+
+    let value = serde_json::from_value(data).map_err(|err| envelope_error(op, err.to_string()))?;
+
+    Ok(Decoded {
+        value,
+        upgraded: v < T::CURRENT_VERSION,
+    })
+}
+
+fn envelope_error(op: &'static str, message: String) -> RepoError {
+    RepoError::Db {
+        op,
+        source: anyhow::anyhow!(message),
+    }
+}
+
+// src/domain/value_objects/validated/example_settings.rs
+// The domain type is plain and current; it knows nothing about versions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExampleSettings {
+    pub notify_channels: Vec<String>,
+    pub quiet_hours: Option<(u8, u8)>,
+}
+
+// src/infra/db/repositories/example_settings_json.rs
+use serde_json::json;
+
+impl VersionedJson for ExampleSettings {
+    const CURRENT_VERSION: u32 = 3;
+    const UPGRADES: &'static [JsonUpgrade] = &[upgrade_v1_to_v2, upgrade_v2_to_v3];
+}
+
+// v1 stored a single `notify_email: bool`.
+fn upgrade_v1_to_v2(mut data: Value) -> Result<Value, String> {
+    let email = data
+        .get("notify_email")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    let object = data.as_object_mut().ok_or("expected an object")?;
+
+    object.remove("notify_email");
+    object.insert(
+        "notify_channels".to_string(),
+        if email { json!(["email"]) } else { json!([]) },
+    );
+    Ok(data)
+}
+
+// v3 added optional quiet hours.
+fn upgrade_v2_to_v3(mut data: Value) -> Result<Value, String> {
+    data.as_object_mut()
+        .ok_or("expected an object")?
+        .entry("quiet_hours")
+        .or_insert(Value::Null);
+    Ok(data)
+}
+
+// In the row conversion:
+//
+// let settings = decode::<ExampleSettings>("example_entity.settings", self.settings)?.value;
+// ...
+// settings: encode("example_entity.settings", entity.settings())?,
+
+// migrations/{timestamp}_create_json_envelope_version/up.sql
+//
+// -- Mirrors `decode`: anything that is not `{"v": <integer>, "data": ...}`,
+// -- including bare objects written before the envelope existed, counts as
+// -- version 1. Checking the type first keeps a malformed "v" from failing the
+// -- cast and with it the whole query.
+// CREATE FUNCTION json_envelope_version(value JSONB) RETURNS INTEGER
+//     LANGUAGE SQL IMMUTABLE
+//     AS $$
+//         SELECT CASE
+//             WHEN jsonb_typeof(value->'v') = 'number'
+//                 AND value ? 'data'
+//                 AND value->>'v' ~ '^[0-9]{1,9}$'
+//             THEN (value->>'v')::int
+//             ELSE 1
+//         END
+//     $$;
+//
+// migrations/{timestamp}_create_json_envelope_version/down.sql
+//
+// DROP FUNCTION json_envelope_version(JSONB);
+
+// src/infra/db/json_envelope.rs (addition)
+// Lets the query builder filter on the stored version without raw SQL.
+diesel::define_sql_function! {
+    fn json_envelope_version(value: diesel::sql_types::Jsonb) -> diesel::sql_types::Integer;
+}
+
+// src/domain/repositories/stored_json_backfill.rs
+use async_trait::async_trait;
+use uuid::Uuid;
+
+pub struct BackfillBatch {
+    pub scanned: usize,
+    pub upgraded: usize,
+    // Cursor for the next call; `None` once a batch comes back short.
+    pub last_id: Option<Uuid>,
+}
+
+#[async_trait]
+pub trait StoredJsonBackfill: Send + Sync {
+    // Rewrites up to `batch_size` stale rows with an ID after `after`. A row
+    // that fails to upgrade is logged and skipped, so it cannot stall the job.
+    async fn upgrade_batch(
+        &self,
+        after: Option<Uuid>,
+        batch_size: i64,
+    ) -> Result<BackfillBatch, RepoError>;
+}
+
+// src/infra/db/repositories/example_settings_backfill.rs
+use std::sync::Arc;
+
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use tracing::warn;
+
+use crate::infra::db::json_envelope::json_envelope_version;
+use crate::infra::db::postgres_connection::PgPool;
+use crate::infra::db::repositories::error_mapping::{map_diesel_error, map_pool_error};
+use crate::infra::db::schema::example_entities;
+
+pub struct ExampleSettingsBackfill {
+    pool: Arc<PgPool>,
+}
+
+impl ExampleSettingsBackfill {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StoredJsonBackfill for ExampleSettingsBackfill {
+    async fn upgrade_batch(
+        &self,
+        after: Option<Uuid>,
+        batch_size: i64,
+    ) -> Result<BackfillBatch, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        let current = ExampleSettings::CURRENT_VERSION as i32;
+
+        let mut query = example_entities::table
+            .filter(json_envelope_version(example_entities::settings).lt(current))
+            .select((example_entities::id, example_entities::settings))
+            .order(example_entities::id)
+            .limit(batch_size)
+            .into_boxed();
+        if let Some(after) = after {
+            query = query.filter(example_entities::id.gt(after));
+        }
+
+        let stale = query
+            .load::<(Uuid, Value)>(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("example_settings.load_stale", err))?;
+
+        let mut batch = BackfillBatch {
+            scanned: stale.len(),
+            upgraded: 0,
+            last_id: None,
+        };
+        if stale.len() as i64 == batch_size {
+            batch.last_id = stale.last().map(|(id, _)| *id);
+        }
+
+        for (id, raw) in stale {
+            let settings = match decode::<ExampleSettings>("example_settings.backfill", raw)
+                .and_then(|decoded| encode("example_settings.backfill", &decoded.value))
+            {
+                Ok(settings) => settings,
+                Err(err) => {
+                    warn!(%id, error = %err, "skipping stored settings that fail to upgrade");
+                    continue;
+                }
+            };
+
+            // The version guard makes the rewrite idempotent: a concurrent save
+            // or another job instance that already wrote the current version wins.
+            batch.upgraded += diesel::update(example_entities::table.find(id))
+                .filter(json_envelope_version(example_entities::settings).lt(current))
+                .set(example_entities::settings.eq(settings))
+                .execute(&mut conn)
+                .await
+                .map_err(|err| map_diesel_error("example_settings.backfill", err))?;
+        }
+
+        Ok(batch)
+    }
+}
+
+// src/usecases/maintenance/backfill_stored_json.rs
+use std::sync::{Mutex, PoisonError};
+
+use crate::usecases::UsecaseError;
+
+#[derive(Clone, Copy)]
+enum BackfillCursor {
+    Start,
+    After(Uuid),
+    Done,
+}
+
+pub struct BackfillStoredJsonUseCase {
+    backfills: Vec<Arc<dyn StoredJsonBackfill>>,
+    cursors: Mutex<Vec<BackfillCursor>>,
+    batch_size: i64,
+}
+
+impl BackfillStoredJsonUseCase {
+    pub fn new(backfills: Vec<Arc<dyn StoredJsonBackfill>>, batch_size: i64) -> Self {
+        let cursors = vec![BackfillCursor::Start; backfills.len()];
+
+        Self {
+            backfills,
+            cursors: Mutex::new(cursors),
+            batch_size,
+        }
+    }
+
+    // One batch per column per tick keeps write load flat; the spawner from
+    // background_job.rs calls this on an interval until it returns 0, which
+    // means every column has been walked once. Rows skipped on the way are in
+    // the logs; fix them and restart the job to pick them up.
+    pub async fn execute(&self) -> Result<usize, UsecaseError> {
+        let mut scanned = 0;
+        for (index, backfill) in self.backfills.iter().enumerate() {
+            let after = match self.cursor(index) {
+                BackfillCursor::Start => None,
+                BackfillCursor::After(id) => Some(id),
+                BackfillCursor::Done => continue,
+            };
+
+            let batch = backfill.upgrade_batch(after, self.batch_size).await?;
+            scanned += batch.scanned;
+
+            let next = batch.last_id.map_or(BackfillCursor::Done, BackfillCursor::After);
+            self.cursors.lock().unwrap_or_else(PoisonError::into_inner)[index] = next;
+        }
+
+        Ok(scanned)
+    }
+
+    fn cursor(&self, index: usize) -> BackfillCursor {
+        self.cursors.lock().unwrap_or_else(PoisonError::into_inner)[index]
+    }
+}
@@ -0,0 +1,174 @@
+// Template: render validation message keys into localized text at the handler
+// boundary. The domain emits `DomainError::invalid_field(field, key)` plus
+// params; this is the only layer that knows about locales and Fluent.
+// Translations live in `locales/{locale}/validation.ftl` at the crate root; see
+// validation_messages.ftl and validation_messages.ja.ftl.
+
+// src/handlers/shared/i18n.rs
+use std::collections::HashMap;
+
+use fluent::concurrent::FluentBundle;
+use fluent::{FluentArgs, FluentResource, FluentValue};
+use unic_langid::LanguageIdentifier;
+
+use crate::handlers::shared::error::ValidationFailure;
+
+pub struct MessageRenderer {
+    bundles: HashMap<LanguageIdentifier, FluentBundle<FluentResource>>,
+    default_locale: LanguageIdentifier,
+}
+
+impl MessageRenderer {
+    pub fn new(
+        default_locale: LanguageIdentifier,
+        sources: &[(LanguageIdentifier, &str)],
+    ) -> anyhow::Result<Self> {
+        let mut bundles = HashMap::new();
+
+        for (locale, source) in sources {
+            let resource = FluentResource::try_new(source.to_string())
+                .map_err(|(_, errors)| anyhow::anyhow!("invalid ftl for {locale}: {errors:?}"))?;
+            let mut bundle = FluentBundle::new_concurrent(vec![locale.clone()]);
+            // Unicode isolation marks around arguments are noise in JSON bodies.
+            bundle.set_use_isolating(false);
+            bundle.add_resource(resource).map_err(|errors| {
+                anyhow::anyhow!("duplicate message ids for {locale}: {errors:?}")
+            })?;
+            bundles.insert(locale.clone(), bundle);
+        }
+
+        anyhow::ensure!(
+            bundles.contains_key(&default_locale),
+            "no bundle for default locale {default_locale}"
+        );
+
+        Ok(Self {
+            bundles,
+            default_locale,
+        })
+    }
+
+    pub fn bundled() -> anyhow::Result<Self> {
+        let en_us: LanguageIdentifier = "en-US".parse()?;
+        let ja: LanguageIdentifier = "ja".parse()?;
+
+        Self::new(
+            en_us.clone(),
+            &[
+                (en_us, include_str!("../../../locales/en-US/validation.ftl")),
+                (ja, include_str!("../../../locales/ja/validation.ftl")),
+            ],
+        )
+    }
+
+    // Takes Accept-Language entries in listed order and ignores q-values. An exact
+    // match wins; otherwise the first bundle with the same language is used.
+    pub fn negotiate(&self, accept_language: Option<&str>) -> LanguageIdentifier {
+        accept_language
+            .into_iter()
+            .flat_map(|header| header.split(','))
+            .filter_map(|entry| entry.split(';').next()?.trim().parse().ok())
+            .find_map(|requested| self.supported(&requested))
+            .unwrap_or_else(|| self.default_locale.clone())
+    }
+
+    // Falls back to the default locale, then to the key itself, so a missing
+    // translation degrades to a stable identifier instead of an empty message.
+    pub fn render(&self, locale: &LanguageIdentifier, failure: &ValidationFailure) -> String {
+        let mut args = FluentArgs::new();
+        args.set("field", failure.field.to_string());
+        for (name, value) in &failure.params {
+            args.set(*name, FluentValue::try_number(value.as_str()));
+        }
+
+        [locale, &self.default_locale]
+            .into_iter()
+            .filter_map(|locale| self.bundles.get(locale))
+            .find_map(|bundle| {
+                let pattern = bundle.get_message(failure.key)?.value()?;
+                let mut errors = Vec::new();
+                let message = bundle.format_pattern(pattern, Some(&args), &mut errors);
+                errors.is_empty().then(|| message.into_owned())
+            })
+            .unwrap_or_else(|| failure.key.to_string())
+    }
+
+    fn supported(&self, requested: &LanguageIdentifier) -> Option<LanguageIdentifier> {
+        if self.bundles.contains_key(requested) {
+            return Some(requested.clone());
+        }
+
+        self.bundles
+            .keys()
+            .find(|locale| locale.language == requested.language)
+            .cloned()
+    }
+}
+
+// src/handlers/app/middleware.rs
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::header::ACCEPT_LANGUAGE,
+    middleware::Next,
+    response::{IntoResponse, Json, Response},
+};
+use serde::Serialize;
+
+use crate::handlers::shared::error::ValidationFailure;
+use crate::handlers::shared::i18n::MessageRenderer;
+
+#[derive(Serialize)]
+struct LocalizedErrorBody {
+    error: &'static str,
+    message: String,
+    field: String,
+    key: &'static str,
+}
+
+pub async fn localize_errors(
+    State(renderer): State<Arc<MessageRenderer>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let accept_language = request
+        .headers()
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok());
+    let locale = renderer.negotiate(accept_language);
+
+    let mut response = next.run(request).await;
+    let Some(failure) = response.extensions_mut().remove::<ValidationFailure>() else {
+        return response;
+    };
+
+    let body = LocalizedErrorBody {
+        error: "VALIDATION_ERROR",
+        message: renderer.render(&locale, &failure),
+        field: failure.field.to_string(),
+        key: failure.key,
+    };
+
+    (response.status(), Json(body)).into_response()
+}
+
+// src/handlers/app/routes.rs
+// `messages: Arc<MessageRenderer>` is built once at startup with
+// `MessageRenderer::bundled()?` and stored on AppState.
+use std::sync::Arc;
+
+use axum::{middleware, Router};
+
+use crate::handlers::app::middleware::localize_errors;
+use crate::handlers::app::state::AppState;
+
+pub fn build_routes(state: AppState) -> Router {
+    Router::new()
+        .nest("/api", crate::handlers::routers::public_api::example_feature::router())
+        .layer(middleware::from_fn_with_state(
+            Arc::clone(&state.messages),
+            localize_errors,
+        ))
+        .with_state(state)
+}
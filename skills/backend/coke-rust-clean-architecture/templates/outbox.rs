@@ -0,0 +1,508 @@
+// Template: transactional outbox. The entity write and its domain events commit
+// in one diesel-async transaction; a relay worker publishes pending rows to the
+// message bus port afterwards. Delivery is at-least-once: a crash between
+// publish and `mark_published` republishes the row, so consumers deduplicate on
+// the outbox event ID.
+//
+// Keep the direction:
+// spawner -> RelayOutboxUseCase -> OutboxRepository + MessageBus (domain ports)
+// infra outbox repository / bus adapter -> domain ports
+
+// migrations/{timestamp}_create_outbox_events/up.sql
+//
+// CREATE TABLE outbox_events (
+//     id UUID PRIMARY KEY,
+//     aggregate_id UUID NOT NULL,
+//     event_type TEXT NOT NULL,
+//     payload JSONB NOT NULL,
+//     occurred_at TIMESTAMPTZ NOT NULL,
+//     attempts INTEGER NOT NULL DEFAULT 0,
+//     next_attempt_at TIMESTAMPTZ NOT NULL,
+//     published_at TIMESTAMPTZ,
+//     failed_at TIMESTAMPTZ,
+//     last_error TEXT
+// );
+//
+// CREATE INDEX outbox_events_pending_idx
+//     ON outbox_events (next_attempt_at)
+//     WHERE published_at IS NULL AND failed_at IS NULL;
+
+// src/domain/events/outbox_event.rs
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::value_objects::ids::id_generator::UuidV7Generator;
+
+crate::define_id!(
+    /// Identifier of an outbox row; also the message ID consumers deduplicate on.
+    OutboxEventId,
+    generator = UuidV7Generator
+);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutboxEvent {
+    id: OutboxEventId,
+    aggregate_id: Uuid,
+    event_type: String,
+    payload: serde_json::Value,
+    occurred_at: DateTime<Utc>,
+    attempts: u32,
+}
+
+impl OutboxEvent {
+    pub fn from_existing(
+        id: OutboxEventId,
+        aggregate_id: Uuid,
+        event_type: String,
+        payload: serde_json::Value,
+        occurred_at: DateTime<Utc>,
+        attempts: u32,
+    ) -> Self {
+        Self {
+            id,
+            aggregate_id,
+            event_type,
+            payload,
+            occurred_at,
+            attempts,
+        }
+    }
+
+    pub fn id(&self) -> &OutboxEventId {
+        &self.id
+    }
+
+    pub fn aggregate_id(&self) -> &Uuid {
+        &self.aggregate_id
+    }
+
+    pub fn event_type(&self) -> &str {
+        &self.event_type
+    }
+
+    pub fn payload(&self) -> &serde_json::Value {
+        &self.payload
+    }
+
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        self.occurred_at
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+}
+
+// src/domain/repositories/outbox_repository.rs
+use async_trait::async_trait;
+
+use crate::domain::repositories::RepoError;
+
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    // Claims up to `limit` due rows by moving their `next_attempt_at` to
+    // `lease_until`. Rows claimed by a crashed relay become due again once the
+    // lease expires.
+    async fn claim_pending(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<OutboxEvent>, RepoError>;
+
+    async fn mark_published(
+        &self,
+        id: &OutboxEventId,
+        published_at: DateTime<Utc>,
+    ) -> Result<(), RepoError>;
+
+    async fn schedule_retry(
+        &self,
+        id: &OutboxEventId,
+        attempts: u32,
+        next_attempt_at: DateTime<Utc>,
+        last_error: &str,
+    ) -> Result<(), RepoError>;
+
+    async fn mark_failed(
+        &self,
+        id: &OutboxEventId,
+        attempts: u32,
+        failed_at: DateTime<Utc>,
+        last_error: &str,
+    ) -> Result<(), RepoError>;
+}
+
+// src/domain/repositories/example_repository.rs
+// Add to ExampleRepository. Events are written in the same transaction as the
+// entity, so a committed change always has its events recorded. Returns
+// `StaleVersion` like `update` when another writer won.
+//
+// async fn update_with_events(
+//     &self,
+//     entity: &ExampleEntity,
+//     events: &[DomainEvent],
+// ) -> Result<(), RepoError>;
+
+// src/domain/services/message_bus.rs
+use crate::domain::services::ServiceError;
+
+#[async_trait]
+pub trait MessageBus: Send + Sync {
+    // Implementations send `event.id()` as the message ID.
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), ServiceError>;
+}
+
+// src/infra/db/repositories/outbox_postgres.rs
+use std::sync::Arc;
+
+use diesel::prelude::*;
+use diesel::result::Error as DieselError;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+
+use crate::domain::events::DomainEvent;
+use crate::infra::db::postgres_connection::PgPool;
+use crate::infra::db::schema::outbox_events;
+
+use super::error_mapping::{map_diesel_error, map_pool_error};
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = outbox_events)]
+struct OutboxEventRow {
+    id: Uuid,
+    aggregate_id: Uuid,
+    event_type: String,
+    payload: serde_json::Value,
+    occurred_at: DateTime<Utc>,
+    attempts: i32,
+}
+
+impl OutboxEventRow {
+    fn into_event(self) -> OutboxEvent {
+        OutboxEvent::from_existing(
+            OutboxEventId::from_uuid(self.id),
+            self.aggregate_id,
+            self.event_type,
+            self.payload,
+            self.occurred_at,
+            u32::try_from(self.attempts).unwrap_or_default(),
+        )
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = outbox_events)]
+pub(crate) struct NewOutboxEventRow {
+    id: Uuid,
+    aggregate_id: Uuid,
+    event_type: &'static str,
+    payload: serde_json::Value,
+    occurred_at: DateTime<Utc>,
+    next_attempt_at: DateTime<Utc>,
+}
+
+impl NewOutboxEventRow {
+    // Serialize before opening the transaction so a bad payload never holds a
+    // connection or rolls back a half-written change.
+    pub(crate) fn from_domain(event: &DomainEvent) -> Result<Self, RepoError> {
+        let payload = serde_json::to_value(event).map_err(|err| RepoError::Db {
+            op: "outbox.serialize",
+            source: anyhow::Error::new(err),
+        })?;
+
+        Ok(Self {
+            id: OutboxEventId::new().into_uuid(),
+            aggregate_id: event.aggregate_id(),
+            event_type: event.name(),
+            payload,
+            occurred_at: event.occurred_at(),
+            next_attempt_at: event.occurred_at(),
+        })
+    }
+}
+
+// Call inside the aggregate repository's transaction.
+pub(crate) async fn insert_outbox_rows(
+    conn: &mut AsyncPgConnection,
+    rows: &[NewOutboxEventRow],
+) -> Result<usize, DieselError> {
+    if rows.is_empty() {
+        return Ok(0);
+    }
+
+    diesel::insert_into(outbox_events::table)
+        .values(rows)
+        .execute(conn)
+        .await
+}
+
+pub struct OutboxPostgres {
+    pool: Arc<PgPool>,
+}
+
+impl OutboxPostgres {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for OutboxPostgres {
+    async fn claim_pending(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<OutboxEvent>, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        // SKIP LOCKED lets several relay instances claim disjoint batches.
+        let due = outbox_events::table
+            .filter(outbox_events::published_at.is_null())
+            .filter(outbox_events::failed_at.is_null())
+            .filter(outbox_events::next_attempt_at.le(now))
+            .order((outbox_events::next_attempt_at, outbox_events::id))
+            .limit(limit)
+            .select(outbox_events::id)
+            .for_update()
+            .skip_locked();
+
+        let rows = diesel::update(outbox_events::table.filter(outbox_events::id.eq_any(due)))
+            .set(outbox_events::next_attempt_at.eq(lease_until))
+            .returning(OutboxEventRow::as_returning())
+            .get_results(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("outbox.claim_pending", err))?;
+
+        Ok(rows.into_iter().map(OutboxEventRow::into_event).collect())
+    }
+
+    async fn mark_published(
+        &self,
+        id: &OutboxEventId,
+        published_at: DateTime<Utc>,
+    ) -> Result<(), RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        diesel::update(outbox_events::table.find(id.as_uuid()))
+            .set(outbox_events::published_at.eq(published_at))
+            .execute(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("outbox.mark_published", err))?;
+
+        Ok(())
+    }
+
+    async fn schedule_retry(
+        &self,
+        id: &OutboxEventId,
+        attempts: u32,
+        next_attempt_at: DateTime<Utc>,
+        last_error: &str,
+    ) -> Result<(), RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        diesel::update(outbox_events::table.find(id.as_uuid()))
+            .set((
+                outbox_events::attempts.eq(i32::try_from(attempts).unwrap_or(i32::MAX)),
+                outbox_events::next_attempt_at.eq(next_attempt_at),
+                outbox_events::last_error.eq(last_error),
+            ))
+            .execute(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("outbox.schedule_retry", err))?;
+
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: &OutboxEventId,
+        attempts: u32,
+        failed_at: DateTime<Utc>,
+        last_error: &str,
+    ) -> Result<(), RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        diesel::update(outbox_events::table.find(id.as_uuid()))
+            .set((
+                outbox_events::attempts.eq(i32::try_from(attempts).unwrap_or(i32::MAX)),
+                outbox_events::failed_at.eq(failed_at),
+                outbox_events::last_error.eq(last_error),
+            ))
+            .execute(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("outbox.mark_failed", err))?;
+
+        Ok(())
+    }
+}
+
+// src/infra/db/repositories/example_postgres.rs
+// Add to `impl ExampleRepository for ExamplePostgres`.
+//
+// use diesel_async::{scoped_futures::ScopedFutureExt, AsyncConnection};
+//
+// use super::outbox_postgres::{insert_outbox_rows, NewOutboxEventRow};
+//
+// async fn update_with_events(
+//     &self,
+//     entity: &ExampleEntity,
+//     events: &[DomainEvent],
+// ) -> Result<(), RepoError> {
+//     let outbox_rows = events
+//         .iter()
+//         .map(NewOutboxEventRow::from_domain)
+//         .collect::<Result<Vec<_>, _>>()?;
+//     let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+//
+//     let result = conn
+//         .transaction::<_, DieselError, _>(|conn| {
+//             async move {
+//                 let rows_affected = diesel::update(
+//                     example_entities::table
+//                         .find(entity.id().as_uuid())
+//                         .filter(example_entities::version.eq(entity.version())),
+//                 )
+//                 .set((
+//                     example_entities::column_text.eq(entity.column_text().as_str()),
+//                     example_entities::column_url.eq(entity.column_url()),
+//                     example_entities::status.eq(entity.status().as_str()),
+//                     example_entities::updated_at.eq(entity.updated_at()),
+//                     example_entities::deleted_at.eq(entity.deleted_at()),
+//                     example_entities::version.eq(example_entities::version + 1),
+//                 ))
+//                 .execute(conn)
+//                 .await?;
+//
+//                 // Rolls back; the entity is missing or its version is stale.
+//                 if rows_affected == 0 {
+//                     return Err(DieselError::NotFound);
+//                 }
+//
+//                 insert_outbox_rows(conn, &outbox_rows).await?;
+//                 Ok(())
+//             }
+//             .scope_boxed()
+//         })
+//         .await;
+//
+//     match result {
+//         // The update matched no row; tell a stale version from a missing entity.
+//         Err(DieselError::NotFound) => Err(Self::stale_or_missing(&mut conn, entity.id()).await),
+//         other => other.map_err(|err| map_diesel_error("example_entity.update_with_events", err)),
+//     }
+// }
+
+// src/usecases/outbox/relay.rs
+use tracing::warn;
+
+use crate::domain::services::Clock;
+use crate::domain::value_objects::RetryPolicy;
+use crate::usecases::UsecaseError;
+
+pub struct RelayOutboxUseCase {
+    outbox_repo: Arc<dyn OutboxRepository>,
+    bus: Arc<dyn MessageBus>,
+    clock: Arc<dyn Clock>,
+    retry: RetryPolicy,
+    batch_size: i64,
+    lease: chrono::Duration,
+}
+
+impl RelayOutboxUseCase {
+    pub fn new(
+        outbox_repo: Arc<dyn OutboxRepository>,
+        bus: Arc<dyn MessageBus>,
+        clock: Arc<dyn Clock>,
+        retry: RetryPolicy,
+        batch_size: i64,
+        lease: chrono::Duration,
+    ) -> Self {
+        Self {
+            outbox_repo,
+            bus,
+            clock,
+            retry,
+            batch_size,
+            lease,
+        }
+    }
+
+    // Returns the number of rows published in this batch.
+    pub async fn execute(&self) -> Result<usize, UsecaseError> {
+        let now = self.clock.now();
+        let claimed = self
+            .outbox_repo
+            .claim_pending(now, now + self.lease, self.batch_size)
+            .await?;
+        let mut published = 0;
+
+        for event in claimed {
+            let Err(err) = self.bus.publish(&event).await else {
+                self.outbox_repo
+                    .mark_published(event.id(), self.clock.now())
+                    .await?;
+                published += 1;
+                continue;
+            };
+
+            let attempt = event.attempts() + 1;
+            let last_error = err.to_string();
+
+            if self.retry.should_retry(attempt) {
+                let delay = chrono::Duration::from_std(self.retry.next_delay(attempt))
+                    .unwrap_or(self.lease);
+                self.outbox_repo
+                    .schedule_retry(event.id(), attempt, self.clock.now() + delay, &last_error)
+                    .await?;
+            } else {
+                warn!(
+                    outbox_event_id = %event.id(),
+                    event_type = event.event_type(),
+                    attempts = attempt,
+                    "outbox event exhausted retries"
+                );
+                self.outbox_repo
+                    .mark_failed(event.id(), attempt, self.clock.now(), &last_error)
+                    .await?;
+            }
+        }
+
+        Ok(published)
+    }
+}
+
+// src/handlers/app/outbox_relay.rs
+// Same spawner shape as background_job.rs.
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info};
+
+pub fn spawn_outbox_relay(
+    usecase: Arc<RelayOutboxUseCase>,
+    cancel: CancellationToken,
+    interval_secs: u64,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        info!("outbox relay started");
+
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => {
+                    info!("outbox relay shutting down");
+                    break;
+                }
+                _ = interval.tick() => {
+                    match usecase.execute().await {
+                        Ok(published) => debug!(published, "outbox relay batch finished"),
+                        Err(err) => error!(error = %err, "outbox relay batch failed"),
+                    }
+                }
+            }
+        }
+    })
+}
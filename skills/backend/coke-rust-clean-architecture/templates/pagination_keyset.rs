@@ -0,0 +1,224 @@
+// Template: keyset (cursor) pagination for large or append-heavy tables. Each
+// page seeks past the last `(created_at, id)` seen instead of skipping rows, so
+// page 1000 costs the same as page 1. There is no total and no jumping to a page
+// number; use pagination_offset.rs when the UI needs either.
+//
+// Back the query with an index matching the filter and order:
+// CREATE INDEX example_entities_owner_keyset_idx
+//     ON example_entities (owner_id, created_at DESC, id DESC);
+
+// src/domain/repositories/pagination.rs
+// Add next to PageRequest and PageResponse.
+use crate::domain::value_objects::PageCursor;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorPage<T> {
+    pub items: Vec<T>,
+    // `None` on the last page.
+    pub next_cursor: Option<PageCursor>,
+}
+
+impl<T> CursorPage<T> {
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> CursorPage<U> {
+        CursorPage {
+            items: self.items.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+        }
+    }
+}
+
+// `ExampleRepository::find_by_owner_after` in repo_trait.rs and
+// repo_diesel_impl.rs is the repository side: it loads `limit + 1` rows after
+// the cursor and turns the extra row into `next_cursor`.
+
+// src/usecases/example_feature/list.rs
+// Add next to ListExampleEntitiesUseCase::execute from pagination_offset.rs.
+use uuid::Uuid;
+
+use crate::domain::repositories::{CursorPage, Visibility};
+use crate::domain::value_objects::PageCursor;
+use crate::usecases::UsecaseError;
+
+impl ListExampleEntitiesUseCase {
+    pub async fn execute_after(
+        &self,
+        owner_id: Uuid,
+        cursor: Option<PageCursor>,
+        limit: u32,
+    ) -> Result<CursorPage<ListExampleEntitiesOutput>, UsecaseError> {
+        let entities = self
+            .example_repo
+            .find_by_owner_after(&owner_id, Visibility::ActiveOnly, cursor.as_ref(), limit)
+            .await?;
+
+        Ok(entities.map(ListExampleEntitiesOutput::from))
+    }
+}
+
+// src/handlers/app/state.rs (addition)
+// Built once at startup from configuration; every instance must share the key
+// or cursors issued by one instance fail on another.
+//
+// pub cursor_key: Arc<PageCursorKey>,
+//
+// cursor_key: Arc::new(PageCursorKey::new(config.cursor_secret)?),
+
+// src/handlers/shared/pagination.rs
+// Add next to the offset Pagination extractor.
+use std::sync::Arc;
+
+use axum::extract::{FromRequestParts, OriginalUri, Query};
+use axum::http::header::{HeaderValue, LINK};
+use axum::http::request::Parts;
+use axum::http::{HeaderMap, Uri};
+use serde::{Deserialize, Serialize};
+use url::form_urlencoded;
+
+use crate::domain::repositories::PageRequest;
+use crate::domain::value_objects::PageCursorKey;
+use crate::domain::DomainError;
+use crate::handlers::app::state::AppState;
+use crate::handlers::shared::error::ApiError;
+use crate::usecases::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+struct CursorParams {
+    cursor: Option<String>,
+    limit: Option<u32>,
+}
+
+// `?cursor=<token>&limit=50`. No cursor means the first page. The limit shares
+// PageRequest's bounds so both pagination styles cap page size the same way.
+pub struct CursorPagination {
+    pub cursor: Option<PageCursor>,
+    pub limit: u32,
+    uri: Uri,
+    key: Arc<PageCursorKey>,
+}
+
+impl CursorPagination {
+    // RFC 8288 `Link: <...>; rel="next"`. Keeps the request path and every
+    // query parameter, such as filters and `limit`; only `cursor` is replaced.
+    pub fn link_header(&self, next_cursor: Option<&PageCursor>) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        let Some(next_cursor) = next_cursor else {
+            return headers;
+        };
+
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        let existing = form_urlencoded::parse(self.uri.query().unwrap_or_default().as_bytes());
+        for (name, value) in existing.filter(|(name, _)| name != "cursor") {
+            query.append_pair(&name, &value);
+        }
+        query.append_pair("cursor", &next_cursor.encode(&self.key));
+
+        let link = format!("<{}?{}>; rel=\"next\"", self.uri.path(), query.finish());
+        if let Ok(value) = HeaderValue::from_str(&link) {
+            headers.insert(LINK, value);
+        }
+
+        headers
+    }
+}
+
+impl FromRequestParts<AppState> for CursorPagination {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let Query(params) = Query::<CursorParams>::from_request_parts(parts, state)
+            .await
+            .map_err(|_| {
+                ApiError::from(UsecaseError::from(DomainError::invalid_field(
+                    "limit",
+                    "validation-invalid-format",
+                )))
+            })?;
+
+        let limit = params.limit.unwrap_or(PageRequest::DEFAULT_PER_PAGE);
+        if limit == 0 || limit > PageRequest::MAX_PER_PAGE {
+            return Err(ApiError::from(UsecaseError::from(
+                DomainError::invalid_field("limit", "validation-out-of-range")
+                    .with_param("max", PageRequest::MAX_PER_PAGE),
+            )));
+        }
+
+        let cursor = params
+            .cursor
+            .as_deref()
+            .map(|token| PageCursor::decode(token, &state.cursor_key))
+            .transpose()
+            .map_err(|err| ApiError::from(UsecaseError::from(err)))?;
+
+        // Nested routers strip their prefix from `parts.uri`; the original URI
+        // keeps the path the client called.
+        let OriginalUri(uri) = OriginalUri::from_request_parts(parts, state)
+            .await
+            .unwrap_or_else(|_| OriginalUri(parts.uri.clone()));
+
+        Ok(Self {
+            cursor,
+            limit,
+            uri,
+            key: Arc::clone(&state.cursor_key),
+        })
+    }
+}
+
+// Response body for cursor pages. The cursor is also in the `Link` header;
+// clients may follow either.
+#[derive(Debug, Serialize)]
+pub struct CursorPageResponse<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+impl<T> CursorPageResponse<T> {
+    pub fn from_page<U>(
+        page: CursorPage<U>,
+        pagination: &CursorPagination,
+        f: impl FnMut(U) -> T,
+    ) -> Self {
+        let next_cursor = page
+            .next_cursor
+            .as_ref()
+            .map(|cursor| cursor.encode(&pagination.key));
+
+        Self {
+            items: page.items.into_iter().map(f).collect(),
+            next_cursor,
+        }
+    }
+}
+
+// src/handlers/routers/public_api/example_action.rs
+use std::sync::Arc;
+
+use axum::{extract::State, Json};
+
+use crate::domain::repositories::ExampleRepository;
+use crate::handlers::app::state::AppState;
+use crate::handlers::shared::auth::AuthenticatedUser;
+use crate::infra::db::repositories::ExamplePostgres;
+use crate::usecases::example_feature::list::ListExampleEntitiesUseCase;
+
+pub async fn list_example_entities_after(
+    State(state): State<AppState>,
+    auth: AuthenticatedUser,
+    pagination: CursorPagination,
+) -> Result<(HeaderMap, Json<CursorPageResponse<ExampleEntitySummaryResponse>>), ApiError> {
+    let example_repo: Arc<dyn ExampleRepository> =
+        Arc::new(ExamplePostgres::new(Arc::clone(&state.db_pool)));
+
+    let usecase = ListExampleEntitiesUseCase::new(example_repo);
+    let page = usecase
+        .execute_after(auth.user_id, pagination.cursor, pagination.limit)
+        .await?;
+
+    let headers = pagination.link_header(page.next_cursor.as_ref());
+    let body = CursorPageResponse::from_page(page, &pagination, ExampleEntitySummaryResponse::from);
+
+    Ok((headers, Json(body)))
+}
@@ -0,0 +1,240 @@
+// Template: offset pagination shared by repositories and handlers. One query
+// returns the page and the total through `COUNT(*) OVER()`. Offset cost grows
+// with the page number; switch large or append-heavy tables to keyset
+// pagination with PageCursor.
+
+// src/domain/repositories/pagination.rs
+use serde::Serialize;
+
+use crate::domain::DomainError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageRequest {
+    page: u32,
+    per_page: u32,
+}
+
+impl PageRequest {
+    pub const DEFAULT_PER_PAGE: u32 = 20;
+    pub const MAX_PER_PAGE: u32 = 100;
+
+    // `page` is 1-based.
+    pub fn new(page: u32, per_page: u32) -> Result<Self, DomainError> {
+        if page == 0 {
+            return Err(DomainError::invalid_field("page", "validation-out-of-range"));
+        }
+
+        if per_page == 0 || per_page > Self::MAX_PER_PAGE {
+            return Err(DomainError::invalid_field("per_page", "validation-out-of-range")
+                .with_param("max", Self::MAX_PER_PAGE));
+        }
+
+        Ok(Self { page, per_page })
+    }
+
+    pub fn page(&self) -> u32 {
+        self.page
+    }
+
+    pub fn per_page(&self) -> u32 {
+        self.per_page
+    }
+
+    pub fn limit(&self) -> i64 {
+        i64::from(self.per_page)
+    }
+
+    pub fn offset(&self) -> i64 {
+        i64::from(self.page - 1) * i64::from(self.per_page)
+    }
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        Self {
+            page: 1,
+            per_page: Self::DEFAULT_PER_PAGE,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PageResponse<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+    pub page: u32,
+    pub per_page: u32,
+}
+
+impl<T> PageResponse<T> {
+    pub fn new(items: Vec<T>, total: i64, request: &PageRequest) -> Self {
+        Self {
+            items,
+            total,
+            page: request.page(),
+            per_page: request.per_page(),
+        }
+    }
+
+    // Maps items across layers (entity -> output -> response DTO).
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> PageResponse<U> {
+        PageResponse {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            page: self.page,
+            per_page: self.per_page,
+        }
+    }
+}
+
+// `ExampleRepository::find_by_owner_page` in repo_trait.rs and
+// repo_diesel_impl.rs is the repository side: one query selects the rows plus
+// `COUNT(*) OVER()`, ordered by `(created_at DESC, id DESC)` so pages are stable.
+
+// src/usecases/example_feature/list.rs
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::ExampleEntity;
+use crate::domain::repositories::{ExampleRepository, PageRequest, PageResponse, Visibility};
+use crate::usecases::UsecaseError;
+
+pub struct ListExampleEntitiesOutput {
+    pub id: Uuid,
+    pub column_text: String,
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ExampleEntity> for ListExampleEntitiesOutput {
+    fn from(entity: ExampleEntity) -> Self {
+        Self {
+            id: entity.id().into_uuid(),
+            column_text: entity.column_text().as_str().to_string(),
+            status: entity.status().as_str().to_string(),
+            created_at: entity.created_at(),
+        }
+    }
+}
+
+pub struct ListExampleEntitiesUseCase {
+    example_repo: Arc<dyn ExampleRepository>,
+}
+
+impl ListExampleEntitiesUseCase {
+    pub fn new(example_repo: Arc<dyn ExampleRepository>) -> Self {
+        Self { example_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        owner_id: Uuid,
+        page: PageRequest,
+    ) -> Result<PageResponse<ListExampleEntitiesOutput>, UsecaseError> {
+        let entities = self
+            .example_repo
+            .find_by_owner_page(&owner_id, Visibility::ActiveOnly, &page)
+            .await?;
+
+        Ok(entities.map(ListExampleEntitiesOutput::from))
+    }
+}
+
+// src/handlers/shared/pagination.rs
+use axum::extract::{FromRequestParts, Query};
+use axum::http::request::Parts;
+use serde::Deserialize;
+
+use crate::domain::repositories::PageRequest;
+use crate::domain::DomainError;
+use crate::handlers::shared::error::ApiError;
+use crate::usecases::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+struct PageParams {
+    page: Option<u32>,
+    per_page: Option<u32>,
+}
+
+// `?page=2&per_page=50`. Missing values use the defaults; out-of-range values
+// become a 400 through the usual DomainError -> ApiError mapping.
+pub struct Pagination(pub PageRequest);
+
+impl<S> FromRequestParts<S> for Pagination
+where
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(params) = Query::<PageParams>::from_request_parts(parts, state)
+            .await
+            .map_err(|_| {
+                ApiError::from(UsecaseError::from(DomainError::invalid_field(
+                    "page",
+                    "validation-invalid-format",
+                )))
+            })?;
+
+        let page = PageRequest::new(
+            params.page.unwrap_or(1),
+            params.per_page.unwrap_or(PageRequest::DEFAULT_PER_PAGE),
+        )
+        .map_err(|err| ApiError::from(UsecaseError::from(err)))?;
+
+        Ok(Self(page))
+    }
+}
+
+// src/handlers/routers/public_api/example_action.rs
+use std::sync::Arc;
+
+use axum::{extract::State, Json};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::repositories::{ExampleRepository, PageResponse};
+use crate::handlers::app::state::AppState;
+use crate::handlers::shared::auth::AuthenticatedUser;
+use crate::handlers::shared::error::ApiError;
+use crate::handlers::shared::pagination::Pagination;
+use crate::infra::db::repositories::ExamplePostgres;
+use crate::usecases::example_feature::list::{
+    ListExampleEntitiesOutput, ListExampleEntitiesUseCase,
+};
+
+#[derive(Debug, Serialize)]
+pub struct ExampleEntitySummaryResponse {
+    pub id: Uuid,
+    pub column_text: String,
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ListExampleEntitiesOutput> for ExampleEntitySummaryResponse {
+    fn from(output: ListExampleEntitiesOutput) -> Self {
+        Self {
+            id: output.id,
+            column_text: output.column_text,
+            status: output.status,
+            created_at: output.created_at,
+        }
+    }
+}
+
+pub async fn list_example_entities(
+    State(state): State<AppState>,
+    auth: AuthenticatedUser,
+    Pagination(page): Pagination,
+) -> Result<Json<PageResponse<ExampleEntitySummaryResponse>>, ApiError> {
+    let example_repo: Arc<dyn ExampleRepository> =
+        Arc::new(ExamplePostgres::new(Arc::clone(&state.db_pool)));
+
+    let usecase = ListExampleEntitiesUseCase::new(example_repo);
+    let output = usecase.execute(auth.user_id, page).await?;
+
+    Ok(Json(output.map(ExampleEntitySummaryResponse::from)))
+}
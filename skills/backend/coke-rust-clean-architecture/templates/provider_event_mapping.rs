@@ -0,0 +1,578 @@
+// Template: anti-corruption layer for third-party event schemas. Each provider
+// gets one mapping module that turns its raw payload, per schema version, into
+// one canonical domain shape. Usecases only ever see ExampleNormalizedEvent, so
+// a provider changing its schema touches one infra module.
+//
+// Version negotiation: the webhook handler reads the provider's schema version
+// (header or body field) and passes it through. Known versions map; a missing
+// version uses the mapper's newest; an unknown version fails with
+// `ServiceError::Unavailable`, so the webhook answers 5xx and the provider
+// retries after a deploy instead of the app guessing at new fields.
+// Pin the account's API version on the provider side and add the new version's
+// mapping (plus a recorded sample payload for it) before moving the pin.
+//
+// Dependency direction:
+// webhook handler -> usecase -> ProviderEventNormalizer (domain port)
+// infra mapping registry + per-provider mappers -> domain port
+
+// src/domain/events/normalized_event.rs
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExampleProvider {
+    ProviderA,
+    ProviderB,
+}
+
+impl ExampleProvider {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ProviderA => "provider_a",
+            Self::ProviderB => "provider_b",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExampleNormalizedEventKind {
+    Succeeded,
+    Failed,
+    Refunded,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExampleNormalizedEvent {
+    pub provider: ExampleProvider,
+    // The provider's event ID; use it as the inbox message ID for dedup.
+    pub external_id: String,
+    pub kind: ExampleNormalizedEventKind,
+    pub subject_ref: String,
+    pub amount_minor: i64,
+    pub currency: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+// src/domain/services/provider_event_normalizer.rs
+use serde_json::Value;
+
+use crate::domain::services::ServiceError;
+
+pub trait ProviderEventNormalizer: Send + Sync {
+    // `Ok(None)` means the event type is deliberately ignored.
+    fn normalize(
+        &self,
+        provider: ExampleProvider,
+        version: Option<&str>,
+        payload: &Value,
+    ) -> Result<Option<ExampleNormalizedEvent>, ServiceError>;
+}
+
+// src/infra/services/provider_mapping/schema_mapper.rs
+pub(crate) trait ProviderSchemaMapper: Send + Sync {
+    fn provider(&self) -> ExampleProvider;
+
+    // Oldest first; the last entry is used when the provider sends no version.
+    fn supported_versions(&self) -> &'static [&'static str];
+
+    fn map(
+        &self,
+        version: &str,
+        payload: &Value,
+    ) -> Result<Option<ExampleNormalizedEvent>, ServiceError>;
+}
+
+pub(crate) fn parse<T>(payload: &Value) -> Result<T, ServiceError>
+where
+    T: for<'de> serde::Deserialize<'de>,
+{
+    T::deserialize(payload)
+        .map_err(|err| ServiceError::InvalidRequest(format!("provider payload: {err}")))
+}
+
+// src/infra/services/provider_mapping/registry.rs
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct ProviderMappingRegistry {
+    mappers: HashMap<ExampleProvider, Arc<dyn ProviderSchemaMapper>>,
+}
+
+impl ProviderMappingRegistry {
+    pub fn new() -> Self {
+        let mappers: [Arc<dyn ProviderSchemaMapper>; 2] =
+            [Arc::new(ProviderAMapper), Arc::new(ProviderBMapper)];
+
+        Self {
+            mappers: mappers
+                .into_iter()
+                .map(|mapper| (mapper.provider(), mapper))
+                .collect(),
+        }
+    }
+}
+
+impl Default for ProviderMappingRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProviderEventNormalizer for ProviderMappingRegistry {
+    fn normalize(
+        &self,
+        provider: ExampleProvider,
+        version: Option<&str>,
+        payload: &Value,
+    ) -> Result<Option<ExampleNormalizedEvent>, ServiceError> {
+        let mapper = self.mappers.get(&provider).ok_or_else(|| {
+            ServiceError::InvalidRequest(format!("no mapper for {}", provider.as_str()))
+        })?;
+        let supported = mapper.supported_versions();
+
+        let version = match version {
+            Some(version) if supported.contains(&version) => version,
+            // Not the provider's fault: we have not shipped this mapping yet.
+            Some(version) => {
+                return Err(ServiceError::Unavailable(format!(
+                    "unsupported {} schema version {version}",
+                    provider.as_str()
+                )));
+            }
+            None => supported.last().copied().ok_or_else(|| {
+                ServiceError::Unavailable(format!("{} mapper has no versions", provider.as_str()))
+            })?,
+        };
+
+        mapper.map(version, payload)
+    }
+}
+
+// src/infra/services/provider_mapping/provider_a.rs
+// Private serde structs mirror the provider's schema per version; only this
+// module knows field names like `amount_cents` or `amount.value`.
+use serde::Deserialize;
+
+pub(crate) struct ProviderAMapper;
+
+#[derive(Deserialize)]
+struct ProviderAV1 {
+    id: String,
+    #[serde(rename = "type")]
+    event_type: String,
+    order_id: String,
+    amount_cents: i64,
+    currency: String,
+    created: i64,
+}
+
+// 2024-06 moved money into a nested object and timestamps to RFC 3339.
+#[derive(Deserialize)]
+struct ProviderAV2 {
+    id: String,
+    #[serde(rename = "type")]
+    event_type: String,
+    order_id: String,
+    amount: ProviderAV2Amount,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+struct ProviderAV2Amount {
+    value: i64,
+    currency: String,
+}
+
+impl ProviderAMapper {
+    fn kind(event_type: &str) -> Option<ExampleNormalizedEventKind> {
+        match event_type {
+            "charge.succeeded" => Some(ExampleNormalizedEventKind::Succeeded),
+            "charge.failed" => Some(ExampleNormalizedEventKind::Failed),
+            "charge.refunded" => Some(ExampleNormalizedEventKind::Refunded),
+            _ => None,
+        }
+    }
+}
+
+impl ProviderSchemaMapper for ProviderAMapper {
+    fn provider(&self) -> ExampleProvider {
+        ExampleProvider::ProviderA
+    }
+
+    fn supported_versions(&self) -> &'static [&'static str] {
+        &["2023-01", "2024-06"]
+    }
+
+    fn map(
+        &self,
+        version: &str,
+        payload: &Value,
+    ) -> Result<Option<ExampleNormalizedEvent>, ServiceError> {
+        let event = match version {
+            "2023-01" => {
+                let raw: ProviderAV1 = parse(payload)?;
+                let Some(kind) = Self::kind(&raw.event_type) else {
+                    return Ok(None);
+                };
+                let occurred_at = DateTime::<Utc>::from_timestamp(raw.created, 0).ok_or_else(|| {
+                    ServiceError::InvalidRequest("provider payload: bad timestamp".to_string())
+                })?;
+
+                ExampleNormalizedEvent {
+                    provider: self.provider(),
+                    external_id: raw.id,
+                    kind,
+                    subject_ref: raw.order_id,
+                    amount_minor: raw.amount_cents,
+                    currency: raw.currency,
+                    occurred_at,
+                }
+            }
+            "2024-06" => {
+                let raw: ProviderAV2 = parse(payload)?;
+                let Some(kind) = Self::kind(&raw.event_type) else {
+                    return Ok(None);
+                };
+
+                ExampleNormalizedEvent {
+                    provider: self.provider(),
+                    external_id: raw.id,
+                    kind,
+                    subject_ref: raw.order_id,
+                    amount_minor: raw.amount.value,
+                    currency: raw.amount.currency,
+                    occurred_at: raw.created_at,
+                }
+            }
+            other => {
+                return Err(ServiceError::Unavailable(format!(
+                    "unsupported provider_a schema version {other}"
+                )));
+            }
+        };
+
+        Ok(Some(event))
+    }
+}
+
+// src/infra/services/provider_mapping/provider_b.rs
+// Provider B sends amounts as strings of minor units and nests the order under `data`.
+pub(crate) struct ProviderBMapper;
+
+#[derive(Deserialize)]
+struct ProviderBV1 {
+    event_id: String,
+    event: String,
+    data: ProviderBV1Data,
+}
+
+#[derive(Deserialize)]
+struct ProviderBV1Data {
+    reference: String,
+    // Minor units as a string, e.g. "1250" for 12.50.
+    amount: String,
+    currency: String,
+    occurred_at: DateTime<Utc>,
+}
+
+impl ProviderBMapper {
+    fn kind(event: &str) -> Option<ExampleNormalizedEventKind> {
+        match event {
+            "payment.completed" => Some(ExampleNormalizedEventKind::Succeeded),
+            "payment.declined" => Some(ExampleNormalizedEventKind::Failed),
+            "payment.reversed" => Some(ExampleNormalizedEventKind::Refunded),
+            _ => None,
+        }
+    }
+}
+
+impl ProviderSchemaMapper for ProviderBMapper {
+    fn provider(&self) -> ExampleProvider {
+        ExampleProvider::ProviderB
+    }
+
+    fn supported_versions(&self) -> &'static [&'static str] {
+        &["v1"]
+    }
+
+    fn map(
+        &self,
+        version: &str,
+        payload: &Value,
+    ) -> Result<Option<ExampleNormalizedEvent>, ServiceError> {
+        if version != "v1" {
+            return Err(ServiceError::Unavailable(format!(
+                "unsupported provider_b schema version {version}"
+            )));
+        }
+
+        let raw: ProviderBV1 = parse(payload)?;
+        let Some(kind) = Self::kind(&raw.event) else {
+            return Ok(None);
+        };
+        let amount_minor = raw.data.amount.parse::<i64>().map_err(|_| {
+            ServiceError::InvalidRequest("provider payload: bad amount".to_string())
+        })?;
+
+        Ok(Some(ExampleNormalizedEvent {
+            provider: self.provider(),
+            external_id: raw.event_id,
+            kind,
+            subject_ref: raw.data.reference,
+            amount_minor,
+            currency: raw.data.currency,
+            occurred_at: raw.data.occurred_at,
+        }))
+    }
+}
+
+// Usage in a webhook usecase, after signature verification:
+//
+// let Some(event) = self.normalizer.normalize(provider, version.as_deref(), &payload)? else {
+//     return Ok(());
+// };
+// self.handle(event).await
+
+// src/infra/services/provider_mapping/mod.rs (addition)
+// #[cfg(test)]
+// mod provider_mapping_test;
+
+// src/infra/services/provider_mapping/provider_mapping_test.rs
+// Fixture tests: one recorded payload per provider and schema version, plus the
+// ways a payload can break that version. Add the new version's sample here
+// before moving the provider-side pin.
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+
+use super::registry::ProviderMappingRegistry;
+use crate::domain::events::{ExampleNormalizedEvent, ExampleNormalizedEventKind, ExampleProvider};
+use crate::domain::services::{ProviderEventNormalizer, ServiceError};
+
+fn normalize(
+    provider: ExampleProvider,
+    version: Option<&str>,
+    payload: Value,
+) -> Result<Option<ExampleNormalizedEvent>, ServiceError> {
+    ProviderMappingRegistry::new().normalize(provider, version, &payload)
+}
+
+fn is_invalid(result: Result<Option<ExampleNormalizedEvent>, ServiceError>) -> bool {
+    matches!(result, Err(ServiceError::InvalidRequest(_)))
+}
+
+fn provider_a_2023_01() -> Value {
+    json!({
+        "id": "evt_1",
+        "type": "charge.succeeded",
+        "order_id": "ord_1",
+        "amount_cents": 1250,
+        "currency": "usd",
+        "created": 1_717_243_200
+    })
+}
+
+fn provider_a_2024_06() -> Value {
+    json!({
+        "id": "evt_2",
+        "type": "charge.refunded",
+        "order_id": "ord_2",
+        "amount": { "value": 990, "currency": "eur" },
+        "created_at": "2024-06-01T12:00:00Z"
+    })
+}
+
+fn provider_b_v1() -> Value {
+    json!({
+        "event_id": "pb_1",
+        "event": "payment.declined",
+        "data": {
+            "reference": "ord_3",
+            "amount": "4200",
+            "currency": "jpy",
+            "occurred_at": "2024-06-01T12:00:00Z"
+        }
+    })
+}
+
+fn occurred_at() -> DateTime<Utc> {
+    "2024-06-01T12:00:00Z".parse().unwrap()
+}
+
+#[test]
+fn maps_provider_a_2023_01() {
+    let event = normalize(ExampleProvider::ProviderA, Some("2023-01"), provider_a_2023_01())
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        event,
+        ExampleNormalizedEvent {
+            provider: ExampleProvider::ProviderA,
+            external_id: "evt_1".to_string(),
+            kind: ExampleNormalizedEventKind::Succeeded,
+            subject_ref: "ord_1".to_string(),
+            amount_minor: 1250,
+            currency: "usd".to_string(),
+            occurred_at: occurred_at(),
+        }
+    );
+}
+
+#[test]
+fn provider_a_2023_01_rejects_missing_and_mistyped_fields() {
+    let mut missing = provider_a_2023_01();
+    missing.as_object_mut().unwrap().remove("order_id");
+    let mut mistyped = provider_a_2023_01();
+    mistyped["amount_cents"] = json!("1250");
+    let mut bad_timestamp = provider_a_2023_01();
+    bad_timestamp["created"] = json!(i64::MAX);
+
+    for payload in [missing, mistyped, bad_timestamp] {
+        assert!(is_invalid(normalize(ExampleProvider::ProviderA, Some("2023-01"), payload)));
+    }
+}
+
+#[test]
+fn maps_provider_a_2024_06() {
+    let event = normalize(ExampleProvider::ProviderA, Some("2024-06"), provider_a_2024_06())
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        event,
+        ExampleNormalizedEvent {
+            provider: ExampleProvider::ProviderA,
+            external_id: "evt_2".to_string(),
+            kind: ExampleNormalizedEventKind::Refunded,
+            subject_ref: "ord_2".to_string(),
+            amount_minor: 990,
+            currency: "eur".to_string(),
+            occurred_at: occurred_at(),
+        }
+    );
+}
+
+#[test]
+fn provider_a_2024_06_rejects_missing_and_mistyped_fields() {
+    let mut missing = provider_a_2024_06();
+    missing["amount"].as_object_mut().unwrap().remove("currency");
+    let mut mistyped = provider_a_2024_06();
+    mistyped["created_at"] = json!(1_717_243_200);
+
+    for payload in [missing, mistyped] {
+        assert!(is_invalid(normalize(ExampleProvider::ProviderA, Some("2024-06"), payload)));
+    }
+}
+
+#[test]
+fn missing_version_uses_newest_mapping() {
+    let event = normalize(ExampleProvider::ProviderA, None, provider_a_2024_06()).unwrap();
+
+    assert_eq!(event.map(|event| event.amount_minor), Some(990));
+}
+
+#[test]
+fn ignores_unmapped_event_type() {
+    let mut payload = provider_a_2024_06();
+    payload["type"] = json!("customer.updated");
+
+    assert_eq!(normalize(ExampleProvider::ProviderA, Some("2024-06"), payload).unwrap(), None);
+}
+
+#[test]
+fn unknown_version_is_unavailable() {
+    let result = normalize(ExampleProvider::ProviderA, Some("2025-01"), provider_a_2024_06());
+
+    assert!(matches!(result, Err(ServiceError::Unavailable(_))));
+}
+
+#[test]
+fn payload_not_matching_version_is_invalid() {
+    let result = normalize(ExampleProvider::ProviderA, Some("2024-06"), provider_a_2023_01());
+
+    assert!(is_invalid(result));
+}
+
+#[test]
+fn maps_provider_b_v1() {
+    let event =
+        normalize(ExampleProvider::ProviderB, Some("v1"), provider_b_v1()).unwrap().unwrap();
+
+    assert_eq!(
+        event,
+        ExampleNormalizedEvent {
+            provider: ExampleProvider::ProviderB,
+            external_id: "pb_1".to_string(),
+            kind: ExampleNormalizedEventKind::Failed,
+            subject_ref: "ord_3".to_string(),
+            amount_minor: 4200,
+            currency: "jpy".to_string(),
+            occurred_at: occurred_at(),
+        }
+    );
+}
+
+#[test]
+fn maps_every_provider_b_v1_event_type() {
+    let cases = [
+        ("payment.completed", ExampleNormalizedEventKind::Succeeded),
+        ("payment.declined", ExampleNormalizedEventKind::Failed),
+        ("payment.reversed", ExampleNormalizedEventKind::Refunded),
+    ];
+
+    for (event_type, kind) in cases {
+        let mut payload = provider_b_v1();
+        payload["event"] = json!(event_type);
+
+        let event = normalize(ExampleProvider::ProviderB, Some("v1"), payload).unwrap();
+
+        assert_eq!(event.map(|event| event.kind), Some(kind), "{event_type}");
+    }
+}
+
+#[test]
+fn provider_b_v1_ignores_unmapped_event_type() {
+    let mut payload = provider_b_v1();
+    payload["event"] = json!("payout.created");
+
+    assert_eq!(normalize(ExampleProvider::ProviderB, Some("v1"), payload).unwrap(), None);
+}
+
+#[test]
+fn provider_b_v1_rejects_missing_fields() {
+    let mut missing_data = provider_b_v1();
+    missing_data.as_object_mut().unwrap().remove("data");
+    let mut missing_reference = provider_b_v1();
+    missing_reference["data"].as_object_mut().unwrap().remove("reference");
+
+    for payload in [missing_data, missing_reference] {
+        assert!(is_invalid(normalize(ExampleProvider::ProviderB, Some("v1"), payload)));
+    }
+}
+
+#[test]
+fn provider_b_v1_rejects_wrong_types() {
+    let mut numeric_amount = provider_b_v1();
+    numeric_amount["data"]["amount"] = json!(4200);
+    let mut decimal_amount = provider_b_v1();
+    decimal_amount["data"]["amount"] = json!("42.00");
+    let mut bad_timestamp = provider_b_v1();
+    bad_timestamp["data"]["occurred_at"] = json!("yesterday");
+
+    for payload in [numeric_amount, decimal_amount, bad_timestamp] {
+        assert!(is_invalid(normalize(ExampleProvider::ProviderB, Some("v1"), payload)));
+    }
+}
+
+#[test]
+fn provider_b_missing_version_uses_v1() {
+    let event = normalize(ExampleProvider::ProviderB, None, provider_b_v1()).unwrap();
+
+    assert_eq!(event.map(|event| event.amount_minor), Some(4200));
+}
+
+#[test]
+fn provider_b_unknown_version_is_unavailable() {
+    let result = normalize(ExampleProvider::ProviderB, Some("v2"), provider_b_v1());
+
+    assert!(matches!(result, Err(ServiceError::Unavailable(_))));
+}
@@ -3,21 +3,36 @@
 // Keep Diesel rows and schema references inside infra. Do not expose rows to
 // domain, usecases, or handlers.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use async_stream::try_stream;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use diesel::dsl::sql;
 use diesel::prelude::*;
-use diesel_async::RunQueryDsl;
+use diesel::sql_types::{BigInt, Bool};
+use diesel::upsert::excluded;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use futures::stream::BoxStream;
+use futures::TryStreamExt;
 use uuid::Uuid;
 
-use crate::domain::entities::ExampleEntity;
-use crate::domain::repositories::{ExampleRepository, RepoError};
-use crate::domain::value_objects::{ExampleEntityId, ExampleEntityName, ExampleEntityStatus};
+use crate::domain::entities::{ExampleEntity, ExampleEntityChanges};
+use crate::domain::repositories::{
+    CursorPage, ExampleEntityFilter, ExampleRepository, PageRequest, PageResponse, RepoError,
+    Specification, UpsertOutcome, Visibility,
+};
+use crate::domain::value_objects::{
+    ExampleEntityId, ExampleEntityName, ExampleEntityStatus, PageCursor,
+};
 use crate::infra::db::postgres_connection::PgPool;
 use crate::infra::db::schema::example_entities;
 
-use super::error_mapping::{map_diesel_error, map_pool_error};
+use super::error_mapping::{map_diesel_error, map_pool_error, ChunkInsertError};
+use super::example_entity_spec::example_entity_predicate;
+use super::visibility::filter_visibility;
 
 #[derive(Queryable, Selectable)]
 #[diesel(table_name = example_entities)]
@@ -30,6 +45,7 @@ struct ExampleEntityRow {
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
     deleted_at: Option<DateTime<Utc>>,
+    version: i32,
 }
 
 impl ExampleEntityRow {
@@ -43,6 +59,7 @@ impl ExampleEntityRow {
             self.created_at,
             self.updated_at,
             self.deleted_at,
+            self.version,
         )
     }
 }
@@ -58,6 +75,7 @@ struct NewExampleEntityRow<'a> {
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
     deleted_at: Option<DateTime<Utc>>,
+    version: i32,
 }
 
 impl<'a> NewExampleEntityRow<'a> {
@@ -71,17 +89,69 @@ impl<'a> NewExampleEntityRow<'a> {
             created_at: entity.created_at(),
             updated_at: entity.updated_at(),
             deleted_at: entity.deleted_at(),
+            version: entity.version(),
+        }
+    }
+}
+
+#[derive(AsChangeset)]
+#[diesel(table_name = example_entities)]
+struct ExampleEntityChangesRow<'a> {
+    column_text: Option<&'a str>,
+    column_url: Option<&'a str>,
+    status: Option<&'a str>,
+    deleted_at: Option<Option<DateTime<Utc>>>,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl<'a> ExampleEntityChangesRow<'a> {
+    fn from_changes(changes: &'a ExampleEntityChanges) -> Self {
+        Self {
+            column_text: changes.column_text().map(ExampleEntityName::as_str),
+            column_url: changes.column_url(),
+            status: changes.status().map(ExampleEntityStatus::as_str),
+            deleted_at: changes.deleted_at(),
+            updated_at: changes.updated_at(),
         }
     }
 }
 
 pub struct ExamplePostgres {
     pool: Arc<PgPool>,
+    insert_chunk_size: usize,
 }
 
 impl ExamplePostgres {
+    // Keeps `IN (...)` lists well under Postgres' 65535 bind-parameter limit.
+    const FIND_BY_IDS_CHUNK: usize = 1000;
+    // 1000 rows x 9 columns = 9000 binds; stay under 65535 / column count.
+    const DEFAULT_INSERT_CHUNK: usize = 1000;
+
     pub fn new(pool: Arc<PgPool>) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            insert_chunk_size: Self::DEFAULT_INSERT_CHUNK,
+        }
+    }
+
+    // Import and seeding tools tune this; request paths keep the default.
+    pub fn with_insert_chunk_size(mut self, size: usize) -> Self {
+        self.insert_chunk_size = size.max(1);
+        self
+    }
+
+    // A versioned update that matched no row either lost a race or targeted a
+    // missing entity; one extra lookup tells the caller which.
+    async fn stale_or_missing(conn: &mut AsyncPgConnection, id: &ExampleEntityId) -> RepoError {
+        let exists = diesel::select(diesel::dsl::exists(example_entities::table.find(id.as_uuid())))
+            .get_result::<bool>(conn)
+            .await;
+
+        match exists {
+            Ok(true) => RepoError::StaleVersion(format!("example entity {id} was modified")),
+            Ok(false) => RepoError::NotFound(format!("example entity {id} not found")),
+            Err(err) => map_diesel_error("example_entity.version_check", err),
+        }
     }
 }
 
@@ -100,6 +170,40 @@ impl ExampleRepository for ExamplePostgres {
         Ok(())
     }
 
+    async fn create_many(&self, entities: &[ExampleEntity]) -> Result<usize, RepoError> {
+        if entities.is_empty() {
+            return Ok(0);
+        }
+
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let rows: Vec<NewExampleEntityRow<'_>> =
+            entities.iter().map(NewExampleEntityRow::from_entity).collect();
+        let chunk_size = self.insert_chunk_size;
+
+        let inserted = conn
+            .transaction::<_, ChunkInsertError, _>(|conn| {
+                async move {
+                    let mut inserted = 0;
+                    for (chunk, chunk_rows) in rows.chunks(chunk_size).enumerate() {
+                        inserted += diesel::insert_into(example_entities::table)
+                            .values(chunk_rows)
+                            .execute(conn)
+                            .await
+                            .map_err(|source| ChunkInsertError {
+                                chunk: Some(chunk),
+                                source,
+                            })?;
+                    }
+                    Ok(inserted)
+                }
+                .scope_boxed()
+            })
+            .await
+            .map_err(|err| err.into_repo_error("example_entity.create_many"))?;
+
+        Ok(inserted)
+    }
+
     async fn find_by_id(&self, id: &ExampleEntityId) -> Result<Option<ExampleEntity>, RepoError> {
         let mut conn = self.pool.get().await.map_err(map_pool_error)?;
 
@@ -113,13 +217,45 @@ impl ExampleRepository for ExamplePostgres {
         Ok(row.map(ExampleEntityRow::into_entity))
     }
 
-    async fn find_by_owner(&self, owner_id: &Uuid) -> Result<Vec<ExampleEntity>, RepoError> {
+    async fn find_by_ids(&self, ids: &[ExampleEntityId]) -> Result<Vec<ExampleEntity>, RepoError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
         let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let mut rows_by_id = HashMap::with_capacity(ids.len());
+
+        for chunk in ids.chunks(Self::FIND_BY_IDS_CHUNK) {
+            let chunk_ids: Vec<Uuid> = chunk.iter().map(|id| *id.as_uuid()).collect();
+            let rows = example_entities::table
+                .filter(example_entities::id.eq_any(&chunk_ids))
+                .load::<ExampleEntityRow>(&mut conn)
+                .await
+                .map_err(|err| map_diesel_error("example_entity.find_by_ids", err))?;
 
-        let rows = example_entities::table
+            rows_by_id.extend(rows.into_iter().map(|row| (row.id, row)));
+        }
+
+        // `IN` returns rows in any order; rebuild the caller's order.
+        Ok(ids
+            .iter()
+            .filter_map(|id| rows_by_id.remove(id.as_uuid()))
+            .map(ExampleEntityRow::into_entity)
+            .collect())
+    }
+
+    async fn find_by_owner(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+    ) -> Result<Vec<ExampleEntity>, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let query = example_entities::table
             .filter(example_entities::owner_id.eq(owner_id))
-            .filter(example_entities::deleted_at.is_null())
-            .order(example_entities::created_at.desc())
+            .into_boxed();
+
+        let rows = filter_visibility(query, example_entities::deleted_at, visibility)
+            .order((example_entities::created_at.desc(), example_entities::id.desc()))
             .load::<ExampleEntityRow>(&mut conn)
             .await
             .map_err(|err| map_diesel_error("example_entity.find_by_owner", err))?;
@@ -127,51 +263,328 @@ impl ExampleRepository for ExamplePostgres {
         Ok(rows.into_iter().map(ExampleEntityRow::into_entity).collect())
     }
 
-    async fn update(&self, entity: &ExampleEntity) -> Result<(), RepoError> {
+    async fn count_by_owner(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+    ) -> Result<i64, RepoError> {
         let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let query = example_entities::table
+            .filter(example_entities::owner_id.eq(owner_id))
+            .into_boxed();
 
-        let rows_affected = diesel::update(example_entities::table.find(entity.id().as_uuid()))
-            .set((
-                example_entities::column_text.eq(entity.column_text().as_str()),
-                example_entities::column_url.eq(entity.column_url()),
-                example_entities::status.eq(entity.status().as_str()),
-                example_entities::updated_at.eq(entity.updated_at()),
-                example_entities::deleted_at.eq(entity.deleted_at()),
-            ))
-            .execute(&mut conn)
+        filter_visibility(query, example_entities::deleted_at, visibility)
+            .count()
+            .get_result::<i64>(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("example_entity.count_by_owner", err))
+    }
+
+    async fn find_by_owner_page(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+        page: &PageRequest,
+    ) -> Result<PageResponse<ExampleEntity>, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let query = example_entities::table
+            .filter(example_entities::owner_id.eq(owner_id))
+            .select((ExampleEntityRow::as_select(), sql::<BigInt>("COUNT(*) OVER()")))
+            .into_boxed();
+
+        // `id` breaks ties so rows with equal timestamps never repeat across pages.
+        let rows = filter_visibility(query, example_entities::deleted_at, visibility)
+            .order((example_entities::created_at.desc(), example_entities::id.desc()))
+            .limit(page.limit())
+            .offset(page.offset())
+            .load::<(ExampleEntityRow, i64)>(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("example_entity.find_by_owner_page", err))?;
+
+        // A page past the end has no rows to carry the window count.
+        let total = match rows.first() {
+            Some((_, total)) => *total,
+            None if page.offset() > 0 => self.count_by_owner(owner_id, visibility).await?,
+            None => 0,
+        };
+        let items = rows.into_iter().map(|(row, _)| row.into_entity()).collect();
+
+        Ok(PageResponse::new(items, total, page))
+    }
+
+    async fn find_by_owner_after(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+        cursor: Option<&PageCursor>,
+        limit: u32,
+    ) -> Result<CursorPage<ExampleEntity>, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let mut query = example_entities::table
+            .filter(example_entities::owner_id.eq(owner_id))
+            .into_boxed();
+
+        // (created_at, id) < (cursor.created_at, cursor.id), spelled out for Diesel.
+        if let Some(cursor) = cursor {
+            let same_instant_earlier_id = example_entities::created_at
+                .eq(cursor.created_at())
+                .and(example_entities::id.lt(cursor.id()));
+            query = query.filter(
+                example_entities::created_at
+                    .lt(cursor.created_at())
+                    .or(same_instant_earlier_id),
+            );
+        }
+
+        // One extra row tells whether another page exists without a COUNT.
+        let mut rows = filter_visibility(query, example_entities::deleted_at, visibility)
+            .order((example_entities::created_at.desc(), example_entities::id.desc()))
+            .limit(i64::from(limit) + 1)
+            .load::<ExampleEntityRow>(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("example_entity.find_by_owner_after", err))?;
+
+        let has_more = rows.len() > limit as usize;
+        rows.truncate(limit as usize);
+
+        let next_cursor = has_more
+            .then(|| rows.last().map(|row| PageCursor::new(row.created_at, row.id)))
+            .flatten();
+        let items = rows.into_iter().map(ExampleEntityRow::into_entity).collect();
+
+        Ok(CursorPage { items, next_cursor })
+    }
+
+    fn stream_by_owner(
+        &self,
+        owner_id: Uuid,
+        visibility: Visibility,
+    ) -> BoxStream<'static, Result<ExampleEntity, RepoError>> {
+        let pool = Arc::clone(&self.pool);
+
+        Box::pin(try_stream! {
+            let mut conn = pool.get().await.map_err(map_pool_error)?;
+            let query = example_entities::table
+                .filter(example_entities::owner_id.eq(owner_id))
+                .into_boxed();
+
+            let mut rows = filter_visibility(query, example_entities::deleted_at, visibility)
+                .order((example_entities::created_at.asc(), example_entities::id.asc()))
+                .load_stream::<ExampleEntityRow>(&mut conn)
+                .await
+                .map_err(|err| map_diesel_error("example_entity.stream_by_owner", err))?;
+
+            while let Some(row) = rows
+                .try_next()
+                .await
+                .map_err(|err| map_diesel_error("example_entity.stream_by_owner", err))?
+            {
+                yield row.into_entity();
+            }
+        })
+    }
+
+    async fn find_matching(
+        &self,
+        spec: &Specification<ExampleEntityFilter>,
+        visibility: Visibility,
+    ) -> Result<Vec<ExampleEntity>, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let query = example_entities::table
+            .filter(example_entity_predicate(spec))
+            .into_boxed();
+
+        let rows = filter_visibility(query, example_entities::deleted_at, visibility)
+            .order((example_entities::created_at.desc(), example_entities::id.desc()))
+            .load::<ExampleEntityRow>(&mut conn)
             .await
-            .map_err(|err| map_diesel_error("example_entity.update", err))?;
+            .map_err(|err| map_diesel_error("example_entity.find_matching", err))?;
+
+        Ok(rows.into_iter().map(ExampleEntityRow::into_entity).collect())
+    }
+
+    async fn count_matching(
+        &self,
+        spec: &Specification<ExampleEntityFilter>,
+        visibility: Visibility,
+    ) -> Result<i64, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let query = example_entities::table
+            .filter(example_entity_predicate(spec))
+            .into_boxed();
+
+        filter_visibility(query, example_entities::deleted_at, visibility)
+            .count()
+            .get_result::<i64>(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("example_entity.count_matching", err))
+    }
+
+    async fn update(&self, entity: &ExampleEntity) -> Result<(), RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        let rows_affected = diesel::update(
+            example_entities::table
+                .find(entity.id().as_uuid())
+                .filter(example_entities::version.eq(entity.version())),
+        )
+        .set((
+            example_entities::column_text.eq(entity.column_text().as_str()),
+            example_entities::column_url.eq(entity.column_url()),
+            example_entities::status.eq(entity.status().as_str()),
+            example_entities::updated_at.eq(entity.updated_at()),
+            example_entities::deleted_at.eq(entity.deleted_at()),
+            example_entities::version.eq(example_entities::version + 1),
+        ))
+        .execute(&mut conn)
+        .await
+        .map_err(|err| map_diesel_error("example_entity.update", err))?;
 
         if rows_affected == 0 {
-            return Err(RepoError::NotFound(format!(
-                "example entity {} not found",
-                entity.id()
-            )));
+            return Err(Self::stale_or_missing(&mut conn, entity.id()).await);
         }
 
         Ok(())
     }
 
-    async fn delete(&self, id: &ExampleEntityId) -> Result<(), RepoError> {
+    async fn upsert(&self, entity: &ExampleEntity) -> Result<UpsertOutcome, RepoError> {
         let mut conn = self.pool.get().await.map_err(map_pool_error)?;
-        let now = Utc::now();
+        let new_row = NewExampleEntityRow::from_entity(entity);
 
-        let rows_affected = diesel::update(example_entities::table.find(id.as_uuid()))
+        // `xmax = 0` is true only for a freshly inserted tuple. No returned row means
+        // the `updated_at` guard kept a newer stored row.
+        let inserted = diesel::insert_into(example_entities::table)
+            .values(&new_row)
+            .on_conflict(example_entities::id)
+            .do_update()
             .set((
-                example_entities::status.eq(ExampleEntityStatus::Inactive.as_str()),
-                example_entities::updated_at.eq(now),
-                example_entities::deleted_at.eq(now),
+                example_entities::column_text.eq(excluded(example_entities::column_text)),
+                example_entities::column_url.eq(excluded(example_entities::column_url)),
+                example_entities::status.eq(excluded(example_entities::status)),
+                example_entities::updated_at.eq(excluded(example_entities::updated_at)),
+                example_entities::deleted_at.eq(excluded(example_entities::deleted_at)),
+                example_entities::version.eq(example_entities::version + 1),
             ))
-            .execute(&mut conn)
+            .filter(example_entities::updated_at.lt(excluded(example_entities::updated_at)))
+            .returning(sql::<Bool>("xmax = 0"))
+            .get_result::<bool>(&mut conn)
             .await
-            .map_err(|err| map_diesel_error("example_entity.delete", err))?;
+            .optional()
+            .map_err(|err| map_diesel_error("example_entity.upsert", err))?;
+
+        Ok(match inserted {
+            Some(true) => UpsertOutcome::Inserted,
+            Some(false) => UpsertOutcome::Updated,
+            None => UpsertOutcome::Skipped,
+        })
+    }
+
+    async fn update_changed(
+        &self,
+        id: &ExampleEntityId,
+        changes: &ExampleEntityChanges,
+    ) -> Result<(), RepoError> {
+        // Diesel rejects an empty changeset, and there is nothing to write anyway.
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let changes_row = ExampleEntityChangesRow::from_changes(changes);
+
+        let rows_affected = diesel::update(
+            example_entities::table
+                .find(id.as_uuid())
+                .filter(example_entities::version.eq(changes.expected_version())),
+        )
+        .set((
+            &changes_row,
+            example_entities::version.eq(example_entities::version + 1),
+        ))
+        .execute(&mut conn)
+        .await
+        .map_err(|err| map_diesel_error("example_entity.update_changed", err))?;
+
+        if rows_affected == 0 {
+            return Err(Self::stale_or_missing(&mut conn, id).await);
+        }
+
+        Ok(())
+    }
+
+    async fn soft_delete(
+        &self,
+        id: &ExampleEntityId,
+        now: DateTime<Utc>,
+    ) -> Result<(), RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        let rows_affected = diesel::update(
+            example_entities::table
+                .find(id.as_uuid())
+                .filter(example_entities::deleted_at.is_null()),
+        )
+        .set((
+            example_entities::status.eq(ExampleEntityStatus::Inactive.as_str()),
+            example_entities::updated_at.eq(now),
+            example_entities::deleted_at.eq(now),
+            example_entities::version.eq(example_entities::version + 1),
+        ))
+        .execute(&mut conn)
+        .await
+        .map_err(|err| map_diesel_error("example_entity.soft_delete", err))?;
+
+        if rows_affected == 0 {
+            return Err(RepoError::NotFound(format!("active example entity {id} not found")));
+        }
+
+        Ok(())
+    }
+
+    async fn restore(&self, id: &ExampleEntityId, now: DateTime<Utc>) -> Result<(), RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        let rows_affected = diesel::update(
+            example_entities::table
+                .find(id.as_uuid())
+                .filter(example_entities::deleted_at.is_not_null()),
+        )
+        .set((
+            example_entities::status.eq(ExampleEntityStatus::Active.as_str()),
+            example_entities::updated_at.eq(now),
+            example_entities::deleted_at.eq(None::<DateTime<Utc>>),
+            example_entities::version.eq(example_entities::version + 1),
+        ))
+        .execute(&mut conn)
+        .await
+        .map_err(|err| map_diesel_error("example_entity.restore", err))?;
 
         if rows_affected == 0 {
-            return Err(RepoError::NotFound(format!("example entity {id} not found")));
+            return Err(RepoError::NotFound(format!("deleted example entity {id} not found")));
         }
 
         Ok(())
     }
+
+    async fn purge_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<usize, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        // Postgres has no DELETE ... LIMIT; bound the batch with a subquery.
+        let batch = example_entities::table
+            .select(example_entities::id)
+            .filter(example_entities::deleted_at.lt(cutoff))
+            .order(example_entities::deleted_at.asc())
+            .limit(limit);
+
+        diesel::delete(example_entities::table.filter(example_entities::id.eq_any(batch)))
+            .execute(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("example_entity.purge_deleted_before", err))
+    }
 }
 
 // Optional generic transaction shape. Keep only when one usecase requires
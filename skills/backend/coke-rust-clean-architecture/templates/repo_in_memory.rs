@@ -0,0 +1,446 @@
+// Template: in-memory implementation of a repository port for usecase tests
+// and the HTTP test harness. It must keep the Postgres implementation's
+// semantics, not just its signatures: the same ordering, `NotFound` on missing
+// rows, `UniqueViolation` on duplicate IDs, `StaleVersion` on version
+// mismatches, and version bumps on every write. When a Postgres method changes
+// behavior, change this file in the same commit.
+//
+// Write one `{Entity}InMemory` per repository trait, next to its Postgres
+// implementation. Stored entities are rebuilt through `from_existing`, so no
+// pending domain events leak between reads.
+
+// src/infra/memory/example_in_memory.rs
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, BoxStream, StreamExt};
+use uuid::Uuid;
+
+use crate::domain::entities::{ExampleEntity, ExampleEntityChanges};
+use crate::domain::repositories::{
+    CursorPage, ExampleEntityFilter, ExampleRepository, PageRequest, PageResponse, RepoError,
+    Specification, UpsertOutcome, Visibility,
+};
+use crate::domain::value_objects::{ExampleEntityId, ExampleEntityStatus, PageCursor};
+
+#[derive(Default)]
+pub struct ExampleInMemory {
+    entities: RwLock<HashMap<Uuid, ExampleEntity>>,
+}
+
+impl ExampleInMemory {
+    // Matches ExamplePostgres::DEFAULT_INSERT_CHUNK so `ChunkFailed` reports the same chunk.
+    const INSERT_CHUNK: usize = 1000;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Seeds rows as-is, bypassing create; use for fixtures such as soft-deleted rows.
+    pub fn with_entities(entities: impl IntoIterator<Item = ExampleEntity>) -> Self {
+        let store = Self::new();
+        store.write().extend(
+            entities
+                .into_iter()
+                .map(|entity| (*entity.id().as_uuid(), stored(&entity))),
+        );
+        store
+    }
+
+    // A panicking test must not poison every later assertion on the store.
+    fn read(&self) -> RwLockReadGuard<'_, HashMap<Uuid, ExampleEntity>> {
+        self.entities.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, HashMap<Uuid, ExampleEntity>> {
+        self.entities.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    // Newest first with `id` as tiebreaker, matching the Postgres list order.
+    fn owned_by(&self, owner_id: &Uuid, visibility: Visibility) -> Vec<ExampleEntity> {
+        let mut entities: Vec<ExampleEntity> = self
+            .read()
+            .values()
+            .filter(|entity| entity.owner_id() == owner_id)
+            .filter(|entity| visibility.includes(entity.is_deleted()))
+            .cloned()
+            .collect();
+        sort_newest_first(&mut entities);
+        entities
+    }
+
+    fn matching(
+        &self,
+        spec: &Specification<ExampleEntityFilter>,
+        visibility: Visibility,
+    ) -> Vec<ExampleEntity> {
+        let mut entities: Vec<ExampleEntity> = self
+            .read()
+            .values()
+            .filter(|entity| spec.is_satisfied_by(*entity))
+            .filter(|entity| visibility.includes(entity.is_deleted()))
+            .cloned()
+            .collect();
+        sort_newest_first(&mut entities);
+        entities
+    }
+}
+
+fn sort_newest_first(entities: &mut [ExampleEntity]) {
+    entities.sort_by_key(|entity| Reverse((entity.created_at(), *entity.id().as_uuid())));
+}
+
+fn stored(entity: &ExampleEntity) -> ExampleEntity {
+    rebuild(
+        entity,
+        entity.status(),
+        entity.updated_at(),
+        entity.deleted_at(),
+        entity.version(),
+    )
+}
+
+fn rebuild(
+    entity: &ExampleEntity,
+    status: ExampleEntityStatus,
+    updated_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+    version: i32,
+) -> ExampleEntity {
+    ExampleEntity::from_existing(
+        *entity.id(),
+        *entity.owner_id(),
+        entity.column_text().clone(),
+        entity.column_url().to_string(),
+        status,
+        entity.created_at(),
+        updated_at,
+        deleted_at,
+        version,
+    )
+}
+
+fn check_version(stored: &ExampleEntity, expected: i32) -> Result<(), RepoError> {
+    if stored.version() != expected {
+        return Err(RepoError::StaleVersion(format!(
+            "example entity {} was modified",
+            stored.id()
+        )));
+    }
+
+    Ok(())
+}
+
+fn not_found(id: &ExampleEntityId) -> RepoError {
+    RepoError::NotFound(format!("example entity {id} not found"))
+}
+
+#[async_trait]
+impl ExampleRepository for ExampleInMemory {
+    async fn create(&self, entity: &ExampleEntity) -> Result<(), RepoError> {
+        let mut entities = self.write();
+        let id = *entity.id().as_uuid();
+
+        if entities.contains_key(&id) {
+            return Err(RepoError::UniqueViolation(format!(
+                "example entity {} already exists",
+                entity.id()
+            )));
+        }
+
+        entities.insert(id, stored(entity));
+        Ok(())
+    }
+
+    async fn create_many(&self, entities: &[ExampleEntity]) -> Result<usize, RepoError> {
+        let mut store = self.write();
+        let mut batch = HashMap::with_capacity(entities.len());
+
+        // Validate the whole batch before writing so a failure leaves the store untouched.
+        for (index, entity) in entities.iter().enumerate() {
+            let id = *entity.id().as_uuid();
+            if store.contains_key(&id) || batch.contains_key(&id) {
+                return Err(RepoError::ChunkFailed {
+                    op: "example_entity.create_many",
+                    chunk: index / Self::INSERT_CHUNK,
+                    source: Box::new(RepoError::UniqueViolation(format!(
+                        "example entity {} already exists",
+                        entity.id()
+                    ))),
+                });
+            }
+            batch.insert(id, stored(entity));
+        }
+
+        let inserted = batch.len();
+        store.extend(batch);
+        Ok(inserted)
+    }
+
+    async fn find_by_id(&self, id: &ExampleEntityId) -> Result<Option<ExampleEntity>, RepoError> {
+        Ok(self.read().get(id.as_uuid()).cloned())
+    }
+
+    async fn find_by_ids(&self, ids: &[ExampleEntityId]) -> Result<Vec<ExampleEntity>, RepoError> {
+        let entities = self.read();
+        let mut seen = HashSet::with_capacity(ids.len());
+
+        Ok(ids
+            .iter()
+            .filter(|id| seen.insert(*id.as_uuid()))
+            .filter_map(|id| entities.get(id.as_uuid()).cloned())
+            .collect())
+    }
+
+    async fn find_by_owner(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+    ) -> Result<Vec<ExampleEntity>, RepoError> {
+        Ok(self.owned_by(owner_id, visibility))
+    }
+
+    async fn count_by_owner(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+    ) -> Result<i64, RepoError> {
+        Ok(self.owned_by(owner_id, visibility).len() as i64)
+    }
+
+    async fn find_by_owner_page(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+        page: &PageRequest,
+    ) -> Result<PageResponse<ExampleEntity>, RepoError> {
+        let entities = self.owned_by(owner_id, visibility);
+        let total = entities.len() as i64;
+        let items = entities
+            .into_iter()
+            .skip(page.offset() as usize)
+            .take(page.limit() as usize)
+            .collect();
+
+        Ok(PageResponse::new(items, total, page))
+    }
+
+    async fn find_by_owner_after(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+        cursor: Option<&PageCursor>,
+        limit: u32,
+    ) -> Result<CursorPage<ExampleEntity>, RepoError> {
+        let mut items: Vec<ExampleEntity> = self
+            .owned_by(owner_id, visibility)
+            .into_iter()
+            .filter(|entity| {
+                cursor.is_none_or(|cursor| {
+                    (entity.created_at(), entity.id().as_uuid())
+                        < (cursor.created_at(), cursor.id())
+                })
+            })
+            .take(limit as usize + 1)
+            .collect();
+
+        let has_more = items.len() > limit as usize;
+        items.truncate(limit as usize);
+
+        let next_cursor = has_more
+            .then(|| {
+                items
+                    .last()
+                    .map(|entity| PageCursor::new(entity.created_at(), *entity.id().as_uuid()))
+            })
+            .flatten();
+
+        Ok(CursorPage { items, next_cursor })
+    }
+
+    fn stream_by_owner(
+        &self,
+        owner_id: Uuid,
+        visibility: Visibility,
+    ) -> BoxStream<'static, Result<ExampleEntity, RepoError>> {
+        // Postgres streams oldest first.
+        let mut entities = self.owned_by(&owner_id, visibility);
+        entities.reverse();
+
+        stream::iter(entities.into_iter().map(Ok)).boxed()
+    }
+
+    async fn find_matching(
+        &self,
+        spec: &Specification<ExampleEntityFilter>,
+        visibility: Visibility,
+    ) -> Result<Vec<ExampleEntity>, RepoError> {
+        Ok(self.matching(spec, visibility))
+    }
+
+    async fn count_matching(
+        &self,
+        spec: &Specification<ExampleEntityFilter>,
+        visibility: Visibility,
+    ) -> Result<i64, RepoError> {
+        Ok(self.matching(spec, visibility).len() as i64)
+    }
+
+    async fn update(&self, entity: &ExampleEntity) -> Result<(), RepoError> {
+        let mut entities = self.write();
+        let current = entities
+            .get(entity.id().as_uuid())
+            .ok_or_else(|| not_found(entity.id()))?;
+        check_version(current, entity.version())?;
+
+        let updated = rebuild(
+            entity,
+            entity.status(),
+            entity.updated_at(),
+            entity.deleted_at(),
+            entity.version() + 1,
+        );
+        entities.insert(*entity.id().as_uuid(), updated);
+        Ok(())
+    }
+
+    async fn upsert(&self, entity: &ExampleEntity) -> Result<UpsertOutcome, RepoError> {
+        let mut entities = self.write();
+        let id = *entity.id().as_uuid();
+
+        let Some(current) = entities.get(&id) else {
+            entities.insert(id, stored(entity));
+            return Ok(UpsertOutcome::Inserted);
+        };
+
+        if current.updated_at() >= entity.updated_at() {
+            return Ok(UpsertOutcome::Skipped);
+        }
+
+        // Owner and creation time are never overwritten, as in the Postgres `SET` list.
+        let updated = ExampleEntity::from_existing(
+            *current.id(),
+            *current.owner_id(),
+            entity.column_text().clone(),
+            entity.column_url().to_string(),
+            entity.status(),
+            current.created_at(),
+            entity.updated_at(),
+            entity.deleted_at(),
+            current.version() + 1,
+        );
+        entities.insert(id, updated);
+        Ok(UpsertOutcome::Updated)
+    }
+
+    async fn update_changed(
+        &self,
+        id: &ExampleEntityId,
+        changes: &ExampleEntityChanges,
+    ) -> Result<(), RepoError> {
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let mut entities = self.write();
+        let current = entities.get(id.as_uuid()).ok_or_else(|| not_found(id))?;
+        check_version(current, changes.expected_version())?;
+
+        let updated = ExampleEntity::from_existing(
+            *current.id(),
+            *current.owner_id(),
+            changes
+                .column_text()
+                .cloned()
+                .unwrap_or_else(|| current.column_text().clone()),
+            changes
+                .column_url()
+                .unwrap_or(current.column_url())
+                .to_string(),
+            changes.status().unwrap_or(current.status()),
+            current.created_at(),
+            changes.updated_at().unwrap_or(current.updated_at()),
+            changes.deleted_at().unwrap_or(current.deleted_at()),
+            current.version() + 1,
+        );
+        entities.insert(*id.as_uuid(), updated);
+        Ok(())
+    }
+
+    async fn soft_delete(
+        &self,
+        id: &ExampleEntityId,
+        now: DateTime<Utc>,
+    ) -> Result<(), RepoError> {
+        let mut entities = self.write();
+        let current = entities
+            .get(id.as_uuid())
+            .filter(|entity| !entity.is_deleted())
+            .ok_or_else(|| RepoError::NotFound(format!("active example entity {id} not found")))?;
+
+        let deleted = rebuild(
+            current,
+            ExampleEntityStatus::Inactive,
+            now,
+            Some(now),
+            current.version() + 1,
+        );
+        entities.insert(*id.as_uuid(), deleted);
+        Ok(())
+    }
+
+    async fn restore(&self, id: &ExampleEntityId, now: DateTime<Utc>) -> Result<(), RepoError> {
+        let mut entities = self.write();
+        let current = entities
+            .get(id.as_uuid())
+            .filter(|entity| entity.is_deleted())
+            .ok_or_else(|| RepoError::NotFound(format!("deleted example entity {id} not found")))?;
+
+        let restored = rebuild(
+            current,
+            ExampleEntityStatus::Active,
+            now,
+            None,
+            current.version() + 1,
+        );
+        entities.insert(*id.as_uuid(), restored);
+        Ok(())
+    }
+
+    async fn purge_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<usize, RepoError> {
+        let mut entities = self.write();
+        let mut expired: Vec<(DateTime<Utc>, Uuid)> = entities
+            .values()
+            .filter_map(|entity| {
+                entity
+                    .deleted_at()
+                    .filter(|deleted_at| *deleted_at < cutoff)
+                    .map(|deleted_at| (deleted_at, *entity.id().as_uuid()))
+            })
+            .collect();
+        expired.sort();
+        expired.truncate(limit.max(0) as usize);
+
+        for (_, id) in &expired {
+            entities.remove(id);
+        }
+
+        Ok(expired.len())
+    }
+}
+
+// Usage in a usecase test:
+//
+// let repo = Arc::new(ExampleInMemory::new());
+// let usecase = CreateExampleEntityUseCase::new(repo.clone(), Arc::new(FixedClock::new(now)));
+//
+// usecase.execute(input).await?;
+//
+// assert_eq!(repo.count_by_owner(&owner_id, Visibility::ActiveOnly).await?, 1);
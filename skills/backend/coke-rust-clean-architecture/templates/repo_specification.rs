@@ -0,0 +1,136 @@
+// Template: composable query specifications. Domain code builds a
+// `Specification<ExampleEntityFilter>` from typed filters; the Diesel repository
+// translates it into one boxed predicate. A new list filter is a new
+// `ExampleEntityFilter` variant plus one match arm in infra, not a new
+// repository method.
+
+// src/domain/repositories/specification.rs
+#[derive(Debug, Clone, PartialEq)]
+pub enum Specification<F> {
+    Filter(F),
+    And(Box<Self>, Box<Self>),
+    Or(Box<Self>, Box<Self>),
+    Not(Box<Self>),
+}
+
+impl<F> Specification<F> {
+    pub fn and(self, other: impl Into<Self>) -> Self {
+        Self::And(Box::new(self), Box::new(other.into()))
+    }
+
+    pub fn or(self, other: impl Into<Self>) -> Self {
+        Self::Or(Box::new(self), Box::new(other.into()))
+    }
+
+    #[expect(clippy::should_implement_trait, reason = "callers chain it without importing Not")]
+    pub fn not(self) -> Self {
+        Self::Not(Box::new(self))
+    }
+}
+
+// Lets in-memory fakes evaluate the same specification the database runs.
+pub trait Filter<T> {
+    fn matches(&self, item: &T) -> bool;
+}
+
+impl<F> Specification<F> {
+    pub fn is_satisfied_by<T>(&self, item: &T) -> bool
+    where
+        F: Filter<T>,
+    {
+        match self {
+            Self::Filter(filter) => filter.matches(item),
+            Self::And(left, right) => left.is_satisfied_by(item) && right.is_satisfied_by(item),
+            Self::Or(left, right) => left.is_satisfied_by(item) || right.is_satisfied_by(item),
+            Self::Not(inner) => !inner.is_satisfied_by(item),
+        }
+    }
+}
+
+// src/domain/repositories/example_entity_filter.rs
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::ExampleEntity;
+use crate::domain::repositories::{Filter, Specification};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExampleEntityFilter {
+    OwnedBy(Uuid),
+    CreatedAfter(DateTime<Utc>),
+    NameContains(String),
+}
+
+impl From<ExampleEntityFilter> for Specification<ExampleEntityFilter> {
+    fn from(filter: ExampleEntityFilter) -> Self {
+        Self::Filter(filter)
+    }
+}
+
+impl Filter<ExampleEntity> for ExampleEntityFilter {
+    fn matches(&self, entity: &ExampleEntity) -> bool {
+        match self {
+            Self::OwnedBy(owner_id) => entity.owner_id() == owner_id,
+            Self::CreatedAfter(instant) => entity.created_at() > *instant,
+            Self::NameContains(text) => entity
+                .column_text()
+                .as_str()
+                .to_lowercase()
+                .contains(&text.to_lowercase()),
+        }
+    }
+}
+
+// Usage in a usecase:
+//
+// let spec = Specification::from(ExampleEntityFilter::OwnedBy(owner_id))
+//     .and(ExampleEntityFilter::CreatedAfter(since))
+//     .and(Specification::from(ExampleEntityFilter::NameContains(draft)).not());
+//
+// let entities = self.example_repo.find_matching(&spec, Visibility::ActiveOnly).await?;
+
+// src/infra/db/repositories/example_entity_spec.rs
+use diesel::dsl::not;
+use diesel::pg::Pg;
+use diesel::prelude::*;
+use diesel::sql_types::Bool;
+
+use crate::domain::repositories::{ExampleEntityFilter, Specification};
+use crate::infra::db::like::escape_like;
+use crate::infra::db::schema::example_entities;
+
+pub(crate) type ExampleEntityPredicate =
+    Box<dyn BoxableExpression<example_entities::table, Pg, SqlType = Bool>>;
+
+pub(crate) fn example_entity_predicate(
+    spec: &Specification<ExampleEntityFilter>,
+) -> ExampleEntityPredicate {
+    match spec {
+        Specification::Filter(filter) => match filter {
+            ExampleEntityFilter::OwnedBy(owner_id) => {
+                Box::new(example_entities::owner_id.eq(*owner_id))
+            }
+            ExampleEntityFilter::CreatedAfter(instant) => {
+                Box::new(example_entities::created_at.gt(*instant))
+            }
+            ExampleEntityFilter::NameContains(text) => Box::new(
+                example_entities::column_text.ilike(format!("%{}%", escape_like(text))),
+            ),
+        },
+        Specification::And(left, right) => {
+            Box::new(example_entity_predicate(left).and(example_entity_predicate(right)))
+        }
+        Specification::Or(left, right) => {
+            Box::new(example_entity_predicate(left).or(example_entity_predicate(right)))
+        }
+        Specification::Not(inner) => Box::new(not(example_entity_predicate(inner))),
+    }
+}
+
+// src/infra/db/like.rs
+// Backend-neutral, so the sqlx and SQLite repositories can use it without Diesel's
+// Postgres types. User text must not act as a LIKE pattern. `\` is PostgreSQL's
+// default escape; SQLite needs `ESCAPE '\'` on the query.
+pub(crate) fn escape_like(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+}
@@ -0,0 +1,763 @@
+// Template: SQLite implementation of a repository port for local development
+// and container-free integration runs. It sits behind the `sqlite` cargo feature
+// and is chosen at startup from the database URL; Postgres stays the production
+// backend and the only one that proves Postgres SQL.
+//
+// [features]
+// sqlite = ["diesel/sqlite", "diesel-async/sqlite", "dep:libsqlite3-sys"]
+//
+// libsqlite3-sys needs `bundled` so contributors need no system SQLite.
+//
+// Keep the semantics of ExamplePostgres: the same ordering, error variants,
+// visibility filtering, and version bumps. Run both through the repository
+// contract suite in coke-tdd-feature-workflow. Known differences:
+// - UUIDs are stored as hyphenated lowercase text, which sorts like the bytes.
+// - `NameContains` uses LIKE, which folds case for ASCII only.
+// - `stream_by_owner` buffers rows; SQLite has no server-side cursor.
+//
+// Postgres migrations stay in `migrations/{timestamp}_.../` like every other
+// template. SQLite migrations live in a sibling `migrations_sqlite/` directory;
+// nesting them under `migrations/` would make the Diesel CLI run them against
+// Postgres. Generate them with `diesel migration generate --migration-dir
+// migrations_sqlite ...`:
+//
+// CREATE TABLE example_entities (
+//     id TEXT PRIMARY KEY NOT NULL,
+//     owner_id TEXT NOT NULL,
+//     column_text TEXT NOT NULL,
+//     column_url TEXT NOT NULL,
+//     status TEXT NOT NULL,
+//     created_at TEXT NOT NULL,
+//     updated_at TEXT NOT NULL,
+//     deleted_at TEXT,
+//     version INTEGER NOT NULL
+// );
+
+// src/infra/db/sqlite/schema.rs
+diesel::table! {
+    example_entities (id) {
+        id -> Text,
+        owner_id -> Text,
+        column_text -> Text,
+        column_url -> Text,
+        status -> Text,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+        deleted_at -> Nullable<TimestamptzSqlite>,
+        version -> Integer,
+    }
+}
+
+// src/infra/db/sqlite/connection.rs
+use diesel::{ConnectionError, SqliteConnection};
+use diesel_async::pooled_connection::deadpool::Pool;
+use diesel_async::pooled_connection::{AsyncDieselConnectionManager, ManagerConfig};
+use diesel_async::sync_connection_wrapper::SyncConnectionWrapper;
+use diesel_async::{AsyncConnection, SimpleAsyncConnection};
+
+use crate::domain::repositories::RepoError;
+use crate::infra::db::repositories::error_mapping::map_pool_error;
+
+pub type SqliteConn = SyncConnectionWrapper<SqliteConnection>;
+pub type SqlitePool = Pool<SqliteConn>;
+
+// Use a file path for local runs. `:memory:` gives each connection its own
+// database, so tests that use it must build the pool with `max_size` 1.
+pub fn build_sqlite_pool(path: &str, max_size: usize) -> Result<SqlitePool, RepoError> {
+    let mut config = ManagerConfig::default();
+    config.custom_setup = Box::new(|path| {
+        let path = path.to_string();
+        Box::pin(async move {
+            let mut conn = SqliteConn::establish(&path).await?;
+            // SQLite has one writer; wait for the lock instead of failing with SQLITE_BUSY.
+            conn.batch_execute(
+                "PRAGMA foreign_keys = ON; PRAGMA journal_mode = WAL; PRAGMA busy_timeout = 5000;",
+            )
+            .await
+            .map_err(ConnectionError::CouldntSetupConfiguration)?;
+            Ok(conn)
+        })
+    });
+
+    let manager = AsyncDieselConnectionManager::<SqliteConn>::new_with_config(path, config);
+    Pool::builder(manager)
+        .max_size(max_size)
+        .build()
+        .map_err(map_pool_error)
+}
+
+// src/infra/db/sqlite/example_sqlite.rs
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_stream::try_stream;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::dsl::{not, sql};
+use diesel::prelude::*;
+use diesel::sql_types::{BigInt, Bool};
+use diesel::sqlite::Sqlite;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::RunQueryDsl;
+use futures::stream::BoxStream;
+use futures::TryStreamExt;
+use uuid::Uuid;
+
+use crate::domain::entities::{ExampleEntity, ExampleEntityChanges};
+use crate::domain::repositories::{
+    CursorPage, ExampleEntityFilter, ExampleRepository, PageRequest, PageResponse, RepoError,
+    Specification, UpsertOutcome, Visibility,
+};
+use crate::domain::value_objects::{
+    ExampleEntityId, ExampleEntityName, ExampleEntityStatus, PageCursor,
+};
+use crate::infra::db::like::escape_like;
+use crate::infra::db::repositories::error_mapping::{
+    map_diesel_error, map_pool_error, ChunkInsertError,
+};
+use crate::infra::db::repositories::visibility::filter_visibility;
+use crate::infra::db::sqlite::connection::{SqliteConn, SqlitePool};
+use crate::infra::db::sqlite::schema::example_entities;
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = example_entities, check_for_backend(Sqlite))]
+struct ExampleEntitySqliteRow {
+    id: String,
+    owner_id: String,
+    column_text: String,
+    column_url: String,
+    status: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+    version: i32,
+}
+
+impl ExampleEntitySqliteRow {
+    fn into_entity(self) -> Result<ExampleEntity, RepoError> {
+        Ok(ExampleEntity::from_existing(
+            ExampleEntityId::from_uuid(parse_uuid(&self.id)?),
+            parse_uuid(&self.owner_id)?,
+            ExampleEntityName::from_trusted(self.column_text),
+            self.column_url,
+            ExampleEntityStatus::from_trusted(self.status),
+            self.created_at,
+            self.updated_at,
+            self.deleted_at,
+            self.version,
+        ))
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = example_entities)]
+struct NewExampleEntitySqliteRow<'a> {
+    id: String,
+    owner_id: String,
+    column_text: &'a str,
+    column_url: &'a str,
+    status: &'a str,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+    version: i32,
+}
+
+impl<'a> NewExampleEntitySqliteRow<'a> {
+    fn from_entity(entity: &'a ExampleEntity) -> Self {
+        Self {
+            id: entity.id().as_uuid().to_string(),
+            owner_id: entity.owner_id().to_string(),
+            column_text: entity.column_text().as_str(),
+            column_url: entity.column_url(),
+            status: entity.status().as_str(),
+            created_at: entity.created_at(),
+            updated_at: entity.updated_at(),
+            deleted_at: entity.deleted_at(),
+            version: entity.version(),
+        }
+    }
+}
+
+#[derive(AsChangeset)]
+#[diesel(table_name = example_entities)]
+struct ExampleEntityChangesSqliteRow<'a> {
+    column_text: Option<&'a str>,
+    column_url: Option<&'a str>,
+    status: Option<&'a str>,
+    deleted_at: Option<Option<DateTime<Utc>>>,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl<'a> ExampleEntityChangesSqliteRow<'a> {
+    fn from_changes(changes: &'a ExampleEntityChanges) -> Self {
+        Self {
+            column_text: changes.column_text().map(ExampleEntityName::as_str),
+            column_url: changes.column_url(),
+            status: changes.status().map(ExampleEntityStatus::as_str),
+            deleted_at: changes.deleted_at(),
+            updated_at: changes.updated_at(),
+        }
+    }
+}
+
+type ExampleEntitySqlitePredicate =
+    Box<dyn BoxableExpression<example_entities::table, Sqlite, SqlType = Bool>>;
+
+// Mirrors example_entity_predicate in example_entity_spec.rs for the text-typed columns.
+fn example_entity_sqlite_predicate(
+    spec: &Specification<ExampleEntityFilter>,
+) -> ExampleEntitySqlitePredicate {
+    match spec {
+        Specification::Filter(filter) => match filter {
+            ExampleEntityFilter::OwnedBy(owner_id) => {
+                Box::new(example_entities::owner_id.eq(owner_id.to_string()))
+            }
+            ExampleEntityFilter::CreatedAfter(instant) => {
+                Box::new(example_entities::created_at.gt(*instant))
+            }
+            ExampleEntityFilter::NameContains(text) => Box::new(
+                example_entities::column_text
+                    .like(format!("%{}%", escape_like(text)))
+                    .escape('\\'),
+            ),
+        },
+        Specification::And(left, right) => Box::new(
+            example_entity_sqlite_predicate(left).and(example_entity_sqlite_predicate(right)),
+        ),
+        Specification::Or(left, right) => Box::new(
+            example_entity_sqlite_predicate(left).or(example_entity_sqlite_predicate(right)),
+        ),
+        Specification::Not(inner) => Box::new(not(example_entity_sqlite_predicate(inner))),
+    }
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, RepoError> {
+    Uuid::parse_str(value).map_err(|err| RepoError::Db {
+        op: "example_entity.parse_uuid",
+        source: err.into(),
+    })
+}
+
+fn into_entities(rows: Vec<ExampleEntitySqliteRow>) -> Result<Vec<ExampleEntity>, RepoError> {
+    rows.into_iter()
+        .map(ExampleEntitySqliteRow::into_entity)
+        .collect()
+}
+
+pub struct ExampleSqlite {
+    pool: Arc<SqlitePool>,
+}
+
+impl ExampleSqlite {
+    // SQLite's default limit is 32766 bind parameters.
+    const FIND_BY_IDS_CHUNK: usize = 1000;
+    // 1000 rows x 9 columns stays under the same limit.
+    const INSERT_CHUNK: usize = 1000;
+
+    pub fn new(pool: Arc<SqlitePool>) -> Self {
+        Self { pool }
+    }
+
+    async fn stale_or_missing(conn: &mut SqliteConn, id: &ExampleEntityId) -> RepoError {
+        let exists = diesel::select(diesel::dsl::exists(
+            example_entities::table.find(id.as_uuid().to_string()),
+        ))
+        .get_result::<bool>(conn)
+        .await;
+
+        match exists {
+            Ok(true) => RepoError::StaleVersion(format!("example entity {id} was modified")),
+            Ok(false) => RepoError::NotFound(format!("example entity {id} not found")),
+            Err(err) => map_diesel_error("example_entity.version_check", err),
+        }
+    }
+}
+
+#[async_trait]
+impl ExampleRepository for ExampleSqlite {
+    async fn create(&self, entity: &ExampleEntity) -> Result<(), RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        diesel::insert_into(example_entities::table)
+            .values(NewExampleEntitySqliteRow::from_entity(entity))
+            .execute(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("example_entity.create", err))?;
+
+        Ok(())
+    }
+
+    async fn create_many(&self, entities: &[ExampleEntity]) -> Result<usize, RepoError> {
+        if entities.is_empty() {
+            return Ok(0);
+        }
+
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let rows: Vec<NewExampleEntitySqliteRow<'_>> = entities
+            .iter()
+            .map(NewExampleEntitySqliteRow::from_entity)
+            .collect();
+
+        // Same all-or-nothing contract as Postgres, reporting the failed chunk.
+        conn.transaction::<_, ChunkInsertError, _>(|conn| {
+            async move {
+                let mut inserted = 0;
+                for (chunk, chunk_rows) in rows.chunks(Self::INSERT_CHUNK).enumerate() {
+                    inserted += diesel::insert_into(example_entities::table)
+                        .values(chunk_rows)
+                        .execute(conn)
+                        .await
+                        .map_err(|source| ChunkInsertError {
+                            chunk: Some(chunk),
+                            source,
+                        })?;
+                }
+                Ok(inserted)
+            }
+            .scope_boxed()
+        })
+        .await
+        .map_err(|err| err.into_repo_error("example_entity.create_many"))
+    }
+
+    async fn find_by_id(&self, id: &ExampleEntityId) -> Result<Option<ExampleEntity>, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        let row = example_entities::table
+            .find(id.as_uuid().to_string())
+            .select(ExampleEntitySqliteRow::as_select())
+            .first(&mut conn)
+            .await
+            .optional()
+            .map_err(|err| map_diesel_error("example_entity.find_by_id", err))?;
+
+        row.map(ExampleEntitySqliteRow::into_entity).transpose()
+    }
+
+    async fn find_by_ids(&self, ids: &[ExampleEntityId]) -> Result<Vec<ExampleEntity>, RepoError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let mut rows_by_id = HashMap::with_capacity(ids.len());
+
+        for chunk in ids.chunks(Self::FIND_BY_IDS_CHUNK) {
+            let chunk_ids: Vec<String> = chunk.iter().map(|id| id.as_uuid().to_string()).collect();
+            let rows = example_entities::table
+                .filter(example_entities::id.eq_any(&chunk_ids))
+                .select(ExampleEntitySqliteRow::as_select())
+                .load(&mut conn)
+                .await
+                .map_err(|err| map_diesel_error("example_entity.find_by_ids", err))?;
+
+            rows_by_id.extend(rows.into_iter().map(|row| (row.id.clone(), row)));
+        }
+
+        ids.iter()
+            .filter_map(|id| rows_by_id.remove(&id.as_uuid().to_string()))
+            .map(ExampleEntitySqliteRow::into_entity)
+            .collect()
+    }
+
+    async fn find_by_owner(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+    ) -> Result<Vec<ExampleEntity>, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let query = example_entities::table
+            .filter(example_entities::owner_id.eq(owner_id.to_string()))
+            .select(ExampleEntitySqliteRow::as_select())
+            .into_boxed();
+
+        let rows = filter_visibility(query, example_entities::deleted_at, visibility)
+            .order((example_entities::created_at.desc(), example_entities::id.desc()))
+            .load(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("example_entity.find_by_owner", err))?;
+
+        into_entities(rows)
+    }
+
+    async fn count_by_owner(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+    ) -> Result<i64, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let query = example_entities::table
+            .filter(example_entities::owner_id.eq(owner_id.to_string()))
+            .into_boxed();
+
+        filter_visibility(query, example_entities::deleted_at, visibility)
+            .count()
+            .get_result::<i64>(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("example_entity.count_by_owner", err))
+    }
+
+    async fn find_by_owner_page(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+        page: &PageRequest,
+    ) -> Result<PageResponse<ExampleEntity>, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let query = example_entities::table
+            .filter(example_entities::owner_id.eq(owner_id.to_string()))
+            .select((ExampleEntitySqliteRow::as_select(), sql::<BigInt>("COUNT(*) OVER()")))
+            .into_boxed();
+
+        let rows = filter_visibility(query, example_entities::deleted_at, visibility)
+            .order((example_entities::created_at.desc(), example_entities::id.desc()))
+            .limit(page.limit())
+            .offset(page.offset())
+            .load::<(ExampleEntitySqliteRow, i64)>(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("example_entity.find_by_owner_page", err))?;
+
+        let total = match rows.first() {
+            Some((_, total)) => *total,
+            None if page.offset() > 0 => self.count_by_owner(owner_id, visibility).await?,
+            None => 0,
+        };
+        let items = into_entities(rows.into_iter().map(|(row, _)| row).collect())?;
+
+        Ok(PageResponse::new(items, total, page))
+    }
+
+    async fn find_by_owner_after(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+        cursor: Option<&PageCursor>,
+        limit: u32,
+    ) -> Result<CursorPage<ExampleEntity>, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let mut query = example_entities::table
+            .filter(example_entities::owner_id.eq(owner_id.to_string()))
+            .select(ExampleEntitySqliteRow::as_select())
+            .into_boxed();
+
+        if let Some(cursor) = cursor {
+            let same_instant_earlier_id = example_entities::created_at
+                .eq(cursor.created_at())
+                .and(example_entities::id.lt(cursor.id().to_string()));
+            query = query.filter(
+                example_entities::created_at
+                    .lt(cursor.created_at())
+                    .or(same_instant_earlier_id),
+            );
+        }
+
+        let mut rows = filter_visibility(query, example_entities::deleted_at, visibility)
+            .order((example_entities::created_at.desc(), example_entities::id.desc()))
+            .limit(i64::from(limit) + 1)
+            .load(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("example_entity.find_by_owner_after", err))?;
+
+        let has_more = rows.len() > limit as usize;
+        rows.truncate(limit as usize);
+
+        let items = into_entities(rows)?;
+        let next_cursor = has_more
+            .then(|| {
+                items
+                    .last()
+                    .map(|entity| PageCursor::new(entity.created_at(), *entity.id().as_uuid()))
+            })
+            .flatten();
+
+        Ok(CursorPage { items, next_cursor })
+    }
+
+    fn stream_by_owner(
+        &self,
+        owner_id: Uuid,
+        visibility: Visibility,
+    ) -> BoxStream<'static, Result<ExampleEntity, RepoError>> {
+        let pool = Arc::clone(&self.pool);
+
+        Box::pin(try_stream! {
+            let mut conn = pool.get().await.map_err(map_pool_error)?;
+            let query = example_entities::table
+                .filter(example_entities::owner_id.eq(owner_id.to_string()))
+                .select(ExampleEntitySqliteRow::as_select())
+                .into_boxed();
+
+            let mut rows = filter_visibility(query, example_entities::deleted_at, visibility)
+                .order((example_entities::created_at.asc(), example_entities::id.asc()))
+                .load_stream::<ExampleEntitySqliteRow>(&mut conn)
+                .await
+                .map_err(|err| map_diesel_error("example_entity.stream_by_owner", err))?;
+
+            while let Some(row) = rows
+                .try_next()
+                .await
+                .map_err(|err| map_diesel_error("example_entity.stream_by_owner", err))?
+            {
+                yield row.into_entity()?;
+            }
+        })
+    }
+
+    async fn find_matching(
+        &self,
+        spec: &Specification<ExampleEntityFilter>,
+        visibility: Visibility,
+    ) -> Result<Vec<ExampleEntity>, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let query = example_entities::table
+            .filter(example_entity_sqlite_predicate(spec))
+            .select(ExampleEntitySqliteRow::as_select())
+            .into_boxed();
+
+        let rows = filter_visibility(query, example_entities::deleted_at, visibility)
+            .order((example_entities::created_at.desc(), example_entities::id.desc()))
+            .load(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("example_entity.find_matching", err))?;
+
+        into_entities(rows)
+    }
+
+    async fn count_matching(
+        &self,
+        spec: &Specification<ExampleEntityFilter>,
+        visibility: Visibility,
+    ) -> Result<i64, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let query = example_entities::table
+            .filter(example_entity_sqlite_predicate(spec))
+            .into_boxed();
+
+        filter_visibility(query, example_entities::deleted_at, visibility)
+            .count()
+            .get_result::<i64>(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("example_entity.count_matching", err))
+    }
+
+    async fn update(&self, entity: &ExampleEntity) -> Result<(), RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        let rows_affected = diesel::update(
+            example_entities::table
+                .find(entity.id().as_uuid().to_string())
+                .filter(example_entities::version.eq(entity.version())),
+        )
+        .set((
+            example_entities::column_text.eq(entity.column_text().as_str()),
+            example_entities::column_url.eq(entity.column_url()),
+            example_entities::status.eq(entity.status().as_str()),
+            example_entities::updated_at.eq(entity.updated_at()),
+            example_entities::deleted_at.eq(entity.deleted_at()),
+            example_entities::version.eq(example_entities::version + 1),
+        ))
+        .execute(&mut conn)
+        .await
+        .map_err(|err| map_diesel_error("example_entity.update", err))?;
+
+        if rows_affected == 0 {
+            return Err(Self::stale_or_missing(&mut conn, entity.id()).await);
+        }
+
+        Ok(())
+    }
+
+    // Two single-statement writes instead of read-then-write in a deferred
+    // transaction: upgrading that transaction's read lock fails with
+    // SQLITE_BUSY under a concurrent writer without waiting on busy_timeout.
+    // Each statement is atomic, so concurrent upserts agree on the outcome the
+    // Postgres `ON CONFLICT` guard would give.
+    async fn upsert(&self, entity: &ExampleEntity) -> Result<UpsertOutcome, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let new_row = NewExampleEntitySqliteRow::from_entity(entity);
+
+        let inserted = diesel::insert_into(example_entities::table)
+            .values(&new_row)
+            .on_conflict(example_entities::id)
+            .do_nothing()
+            .execute(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("example_entity.upsert", err))?;
+
+        if inserted > 0 {
+            return Ok(UpsertOutcome::Inserted);
+        }
+
+        let updated = diesel::update(
+            example_entities::table
+                .find(&new_row.id)
+                .filter(example_entities::updated_at.lt(new_row.updated_at)),
+        )
+        .set((
+            example_entities::column_text.eq(new_row.column_text),
+            example_entities::column_url.eq(new_row.column_url),
+            example_entities::status.eq(new_row.status),
+            example_entities::updated_at.eq(new_row.updated_at),
+            example_entities::deleted_at.eq(new_row.deleted_at),
+            example_entities::version.eq(example_entities::version + 1),
+        ))
+        .execute(&mut conn)
+        .await
+        .map_err(|err| map_diesel_error("example_entity.upsert", err))?;
+
+        Ok(if updated > 0 {
+            UpsertOutcome::Updated
+        } else {
+            UpsertOutcome::Skipped
+        })
+    }
+
+    async fn update_changed(
+        &self,
+        id: &ExampleEntityId,
+        changes: &ExampleEntityChanges,
+    ) -> Result<(), RepoError> {
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+        let changes_row = ExampleEntityChangesSqliteRow::from_changes(changes);
+
+        let rows_affected = diesel::update(
+            example_entities::table
+                .find(id.as_uuid().to_string())
+                .filter(example_entities::version.eq(changes.expected_version())),
+        )
+        .set((
+            &changes_row,
+            example_entities::version.eq(example_entities::version + 1),
+        ))
+        .execute(&mut conn)
+        .await
+        .map_err(|err| map_diesel_error("example_entity.update_changed", err))?;
+
+        if rows_affected == 0 {
+            return Err(Self::stale_or_missing(&mut conn, id).await);
+        }
+
+        Ok(())
+    }
+
+    async fn soft_delete(
+        &self,
+        id: &ExampleEntityId,
+        now: DateTime<Utc>,
+    ) -> Result<(), RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        let rows_affected = diesel::update(
+            example_entities::table
+                .find(id.as_uuid().to_string())
+                .filter(example_entities::deleted_at.is_null()),
+        )
+        .set((
+            example_entities::status.eq(ExampleEntityStatus::Inactive.as_str()),
+            example_entities::updated_at.eq(now),
+            example_entities::deleted_at.eq(now),
+            example_entities::version.eq(example_entities::version + 1),
+        ))
+        .execute(&mut conn)
+        .await
+        .map_err(|err| map_diesel_error("example_entity.soft_delete", err))?;
+
+        if rows_affected == 0 {
+            return Err(RepoError::NotFound(format!("active example entity {id} not found")));
+        }
+
+        Ok(())
+    }
+
+    async fn restore(&self, id: &ExampleEntityId, now: DateTime<Utc>) -> Result<(), RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        let rows_affected = diesel::update(
+            example_entities::table
+                .find(id.as_uuid().to_string())
+                .filter(example_entities::deleted_at.is_not_null()),
+        )
+        .set((
+            example_entities::status.eq(ExampleEntityStatus::Active.as_str()),
+            example_entities::updated_at.eq(now),
+            example_entities::deleted_at.eq(None::<DateTime<Utc>>),
+            example_entities::version.eq(example_entities::version + 1),
+        ))
+        .execute(&mut conn)
+        .await
+        .map_err(|err| map_diesel_error("example_entity.restore", err))?;
+
+        if rows_affected == 0 {
+            return Err(RepoError::NotFound(format!("deleted example entity {id} not found")));
+        }
+
+        Ok(())
+    }
+
+    async fn purge_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<usize, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        // `DELETE ... LIMIT` needs a compile-time SQLite option; use the same subquery.
+        let batch = example_entities::table
+            .select(example_entities::id)
+            .filter(example_entities::deleted_at.lt(cutoff))
+            .order(example_entities::deleted_at.asc())
+            .limit(limit);
+
+        diesel::delete(example_entities::table.filter(example_entities::id.eq_any(batch)))
+            .execute(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("example_entity.purge_deleted_before", err))
+    }
+}
+
+// src/infra/db/database.rs
+// Picks the backend once at startup. Handlers take repositories from here
+// instead of naming ExamplePostgres:
+//
+// let example_repo = state.database.example_repo();
+use crate::infra::db::postgres_connection::{build_pool, PgPool};
+use crate::infra::db::repositories::ExamplePostgres;
+
+#[derive(Clone)]
+pub enum Database {
+    Postgres(Arc<PgPool>),
+    #[cfg(feature = "sqlite")]
+    Sqlite(Arc<SqlitePool>),
+}
+
+impl Database {
+    // `sqlite://path/to/dev.db` selects SQLite; anything else is a Postgres URL.
+    pub fn connect(url: &str, max_size: usize) -> Result<Self, RepoError> {
+        #[cfg(feature = "sqlite")]
+        if let Some(path) = url.strip_prefix("sqlite://") {
+            return Ok(Self::Sqlite(Arc::new(build_sqlite_pool(path, max_size)?)));
+        }
+
+        // `build_pool` stands in for the project's existing Postgres pool builder.
+        Ok(Self::Postgres(Arc::new(build_pool(url, max_size)?)))
+    }
+
+    pub fn example_repo(&self) -> Arc<dyn ExampleRepository> {
+        match self {
+            Self::Postgres(pool) => Arc::new(ExamplePostgres::new(Arc::clone(pool))),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(pool) => Arc::new(ExampleSqlite::new(Arc::clone(pool))),
+        }
+    }
+}
+
+// Migrations run per backend at startup:
+//
+// const PG_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+// #[cfg(feature = "sqlite")]
+// const SQLITE_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations_sqlite");
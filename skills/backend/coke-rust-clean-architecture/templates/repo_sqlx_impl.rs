@@ -0,0 +1,728 @@
+// Template: sqlx implementation of a repository port, for teams that want SQL
+// strings checked against the database at compile time instead of Diesel's DSL.
+// It implements the same domain trait, so domain, usecases, and handlers do not
+// change. Pick one implementation per build with cargo features:
+//
+// [features]
+// default = ["diesel-repos"]
+// diesel-repos = ["dep:diesel", "dep:diesel-async"]
+// sqlx-repos = ["dep:sqlx"]
+//
+// sqlx needs `runtime-tokio`, `postgres`, `uuid`, `chrono`, and `macros`.
+//
+// - Both implementations read the same Postgres migrations; sqlx needs no schema.rs.
+// - Commit the `.sqlx/` query cache from `cargo sqlx prepare` and build CI with
+//   `SQLX_OFFLINE=true`, so builds do not need a live database.
+// - Query macros need fixed SQL. Dynamic predicates (specifications) use
+//   `QueryBuilder` with binds and are checked by the contract suite instead.
+// - Keep the semantics of ExamplePostgres and run both through the repository
+//   contract suite in coke-tdd-feature-workflow.
+
+// src/infra/db/sqlx/error_mapping.rs
+use crate::domain::repositories::RepoError;
+
+pub(crate) fn map_sqlx_error(op: &'static str, err: sqlx::Error) -> RepoError {
+    match &err {
+        sqlx::Error::RowNotFound => RepoError::NotFound(format!("{op} returned no rows")),
+        sqlx::Error::Database(db) if db.is_unique_violation() => {
+            RepoError::UniqueViolation(db.message().to_string())
+        }
+        sqlx::Error::Database(db) if db.is_foreign_key_violation() => {
+            RepoError::ForeignKeyViolation(db.message().to_string())
+        }
+        // Serialization failure and deadlock.
+        sqlx::Error::Database(db) if matches!(db.code().as_deref(), Some("40001" | "40P01")) => {
+            RepoError::Transient {
+                op,
+                source: anyhow::Error::new(err),
+            }
+        }
+        // Nothing reached the server. `Io` and `PoolClosed` stay `Db`: an I/O error can
+        // arrive after the server committed, so a retry could replay the write.
+        sqlx::Error::PoolTimedOut => RepoError::ConnectionError(err.to_string()),
+        _ => RepoError::Db {
+            op,
+            source: anyhow::Error::new(err),
+        },
+    }
+}
+
+// src/infra/db/sqlx/example_sqlx.rs
+use std::collections::HashMap;
+
+use async_stream::try_stream;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+use futures::TryStreamExt;
+use sqlx::{PgPool, Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use crate::domain::entities::{ExampleEntity, ExampleEntityChanges};
+use crate::domain::repositories::{
+    CursorPage, ExampleEntityFilter, ExampleRepository, PageRequest, PageResponse, RepoError,
+    Specification, UpsertOutcome, Visibility,
+};
+use crate::domain::value_objects::{
+    ExampleEntityId, ExampleEntityName, ExampleEntityStatus, PageCursor,
+};
+use crate::infra::db::like::escape_like;
+use crate::infra::db::sqlx::error_mapping::map_sqlx_error;
+
+// FromRow serves the QueryBuilder queries; the query macros map by column name.
+#[derive(sqlx::FromRow)]
+struct ExampleEntitySqlxRow {
+    id: Uuid,
+    owner_id: Uuid,
+    column_text: String,
+    column_url: String,
+    status: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+    version: i32,
+}
+
+impl ExampleEntitySqlxRow {
+    fn into_entity(self) -> ExampleEntity {
+        ExampleEntity::from_existing(
+            ExampleEntityId::from_uuid(self.id),
+            self.owner_id,
+            ExampleEntityName::from_trusted(self.column_text),
+            self.column_url,
+            ExampleEntityStatus::from_trusted(self.status),
+            self.created_at,
+            self.updated_at,
+            self.deleted_at,
+            self.version,
+        )
+    }
+}
+
+// Visibility becomes two binds so every list query stays one static SQL string:
+// `AND ((deleted_at IS NULL AND $active) OR (deleted_at IS NOT NULL AND $deleted))`.
+fn visibility_binds(visibility: Visibility) -> (bool, bool) {
+    (visibility.includes(false), visibility.includes(true))
+}
+
+fn push_example_entity_predicate(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    spec: &Specification<ExampleEntityFilter>,
+) {
+    match spec {
+        Specification::Filter(filter) => match filter {
+            ExampleEntityFilter::OwnedBy(owner_id) => {
+                builder.push("owner_id = ").push_bind(*owner_id);
+            }
+            ExampleEntityFilter::CreatedAfter(instant) => {
+                builder.push("created_at > ").push_bind(*instant);
+            }
+            ExampleEntityFilter::NameContains(text) => {
+                builder
+                    .push("column_text ILIKE ")
+                    .push_bind(format!("%{}%", escape_like(text)));
+            }
+        },
+        Specification::And(left, right) => {
+            builder.push("(");
+            push_example_entity_predicate(builder, left);
+            builder.push(" AND ");
+            push_example_entity_predicate(builder, right);
+            builder.push(")");
+        }
+        Specification::Or(left, right) => {
+            builder.push("(");
+            push_example_entity_predicate(builder, left);
+            builder.push(" OR ");
+            push_example_entity_predicate(builder, right);
+            builder.push(")");
+        }
+        Specification::Not(inner) => {
+            builder.push("NOT (");
+            push_example_entity_predicate(builder, inner);
+            builder.push(")");
+        }
+    }
+}
+
+fn matching_query<'a>(
+    select: &str,
+    spec: &'a Specification<ExampleEntityFilter>,
+    visibility: Visibility,
+) -> QueryBuilder<'a, Postgres> {
+    let (active, deleted) = visibility_binds(visibility);
+    let mut builder = QueryBuilder::new(select);
+    builder.push(" FROM example_entities WHERE ");
+    push_example_entity_predicate(&mut builder, spec);
+    builder
+        .push(" AND ((deleted_at IS NULL AND ")
+        .push_bind(active)
+        .push(") OR (deleted_at IS NOT NULL AND ")
+        .push_bind(deleted)
+        .push("))");
+    builder
+}
+
+pub struct ExampleSqlx {
+    pool: PgPool,
+}
+
+impl ExampleSqlx {
+    // UNNEST binds one array per column, so this bounds memory, not bind count.
+    const INSERT_CHUNK: usize = 1000;
+
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn stale_or_missing(&self, id: &ExampleEntityId) -> RepoError {
+        let exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM example_entities WHERE id = $1) AS "exists!""#,
+            id.as_uuid(),
+        )
+        .fetch_one(&self.pool)
+        .await;
+
+        match exists {
+            Ok(true) => RepoError::StaleVersion(format!("example entity {id} was modified")),
+            Ok(false) => RepoError::NotFound(format!("example entity {id} not found")),
+            Err(err) => map_sqlx_error("example_entity.version_check", err),
+        }
+    }
+}
+
+#[async_trait]
+impl ExampleRepository for ExampleSqlx {
+    async fn create(&self, entity: &ExampleEntity) -> Result<(), RepoError> {
+        sqlx::query!(
+            "INSERT INTO example_entities \
+             (id, owner_id, column_text, column_url, status, created_at, updated_at, \
+              deleted_at, version) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+            entity.id().as_uuid(),
+            entity.owner_id(),
+            entity.column_text().as_str(),
+            entity.column_url(),
+            entity.status().as_str(),
+            entity.created_at(),
+            entity.updated_at(),
+            entity.deleted_at(),
+            entity.version(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|err| map_sqlx_error("example_entity.create", err))?;
+
+        Ok(())
+    }
+
+    async fn create_many(&self, entities: &[ExampleEntity]) -> Result<usize, RepoError> {
+        if entities.is_empty() {
+            return Ok(0);
+        }
+
+        let op = "example_entity.create_many";
+        let mut tx = self.pool.begin().await.map_err(|err| map_sqlx_error(op, err))?;
+        let mut inserted = 0;
+
+        for (chunk, chunk_entities) in entities.chunks(Self::INSERT_CHUNK).enumerate() {
+            let ids: Vec<Uuid> = chunk_entities
+                .iter()
+                .map(|entity| *entity.id().as_uuid())
+                .collect();
+            let owner_ids: Vec<Uuid> = chunk_entities
+                .iter()
+                .map(|entity| *entity.owner_id())
+                .collect();
+            let texts: Vec<&str> = chunk_entities
+                .iter()
+                .map(|entity| entity.column_text().as_str())
+                .collect();
+            let urls: Vec<&str> = chunk_entities
+                .iter()
+                .map(|entity| entity.column_url())
+                .collect();
+            let statuses: Vec<&str> = chunk_entities
+                .iter()
+                .map(|entity| entity.status().as_str())
+                .collect();
+            let created: Vec<DateTime<Utc>> = chunk_entities
+                .iter()
+                .map(|entity| entity.created_at())
+                .collect();
+            let updated: Vec<DateTime<Utc>> = chunk_entities
+                .iter()
+                .map(|entity| entity.updated_at())
+                .collect();
+            let deleted: Vec<Option<DateTime<Utc>>> = chunk_entities
+                .iter()
+                .map(|entity| entity.deleted_at())
+                .collect();
+            let versions: Vec<i32> = chunk_entities
+                .iter()
+                .map(|entity| entity.version())
+                .collect();
+
+            let result = sqlx::query!(
+                "INSERT INTO example_entities \
+                 (id, owner_id, column_text, column_url, status, created_at, updated_at, \
+                  deleted_at, version) \
+                 SELECT * FROM UNNEST($1::uuid[], $2::uuid[], $3::text[], $4::text[], \
+                  $5::text[], $6::timestamptz[], $7::timestamptz[], $8::timestamptz[], \
+                  $9::int4[])",
+                &ids,
+                &owner_ids,
+                &texts,
+                &urls,
+                &statuses,
+                &created,
+                &updated,
+                &deleted,
+                &versions,
+            )
+            .execute(&mut *tx)
+            .await;
+
+            // Dropping `tx` rolls back the chunks already written.
+            inserted += result
+                .map_err(|err| RepoError::ChunkFailed {
+                    op,
+                    chunk,
+                    source: Box::new(map_sqlx_error(op, err)),
+                })?
+                .rows_affected() as usize;
+        }
+
+        tx.commit().await.map_err(|err| map_sqlx_error(op, err))?;
+        Ok(inserted)
+    }
+
+    async fn find_by_id(&self, id: &ExampleEntityId) -> Result<Option<ExampleEntity>, RepoError> {
+        let row = sqlx::query_as!(
+            ExampleEntitySqlxRow,
+            "SELECT id, owner_id, column_text, column_url, status, created_at, updated_at, \
+             deleted_at, version FROM example_entities WHERE id = $1",
+            id.as_uuid(),
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| map_sqlx_error("example_entity.find_by_id", err))?;
+
+        Ok(row.map(ExampleEntitySqlxRow::into_entity))
+    }
+
+    async fn find_by_ids(&self, ids: &[ExampleEntityId]) -> Result<Vec<ExampleEntity>, RepoError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // `= ANY($1)` binds one array, so there is no parameter limit to chunk around.
+        let uuids: Vec<Uuid> = ids.iter().map(|id| *id.as_uuid()).collect();
+        let rows = sqlx::query_as!(
+            ExampleEntitySqlxRow,
+            "SELECT id, owner_id, column_text, column_url, status, created_at, updated_at, \
+             deleted_at, version FROM example_entities WHERE id = ANY($1)",
+            &uuids,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| map_sqlx_error("example_entity.find_by_ids", err))?;
+
+        let mut rows_by_id: HashMap<Uuid, ExampleEntitySqlxRow> =
+            rows.into_iter().map(|row| (row.id, row)).collect();
+
+        Ok(ids
+            .iter()
+            .filter_map(|id| rows_by_id.remove(id.as_uuid()))
+            .map(ExampleEntitySqlxRow::into_entity)
+            .collect())
+    }
+
+    async fn find_by_owner(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+    ) -> Result<Vec<ExampleEntity>, RepoError> {
+        let (active, deleted) = visibility_binds(visibility);
+
+        let rows = sqlx::query_as!(
+            ExampleEntitySqlxRow,
+            "SELECT id, owner_id, column_text, column_url, status, created_at, updated_at, \
+             deleted_at, version FROM example_entities \
+             WHERE owner_id = $1 \
+               AND ((deleted_at IS NULL AND $2) OR (deleted_at IS NOT NULL AND $3)) \
+             ORDER BY created_at DESC, id DESC",
+            owner_id,
+            active,
+            deleted,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| map_sqlx_error("example_entity.find_by_owner", err))?;
+
+        Ok(rows.into_iter().map(ExampleEntitySqlxRow::into_entity).collect())
+    }
+
+    async fn count_by_owner(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+    ) -> Result<i64, RepoError> {
+        let (active, deleted) = visibility_binds(visibility);
+
+        sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!" FROM example_entities
+               WHERE owner_id = $1
+                 AND ((deleted_at IS NULL AND $2) OR (deleted_at IS NOT NULL AND $3))"#,
+            owner_id,
+            active,
+            deleted,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|err| map_sqlx_error("example_entity.count_by_owner", err))
+    }
+
+    async fn find_by_owner_page(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+        page: &PageRequest,
+    ) -> Result<PageResponse<ExampleEntity>, RepoError> {
+        let (active, deleted) = visibility_binds(visibility);
+
+        let records = sqlx::query!(
+            r#"SELECT id, owner_id, column_text, column_url, status, created_at, updated_at,
+                      deleted_at, version, COUNT(*) OVER() AS "total!"
+               FROM example_entities
+               WHERE owner_id = $1
+                 AND ((deleted_at IS NULL AND $2) OR (deleted_at IS NOT NULL AND $3))
+               ORDER BY created_at DESC, id DESC
+               LIMIT $4 OFFSET $5"#,
+            owner_id,
+            active,
+            deleted,
+            page.limit(),
+            page.offset(),
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| map_sqlx_error("example_entity.find_by_owner_page", err))?;
+
+        // A page past the end has no rows to carry the window count.
+        let total = match records.first() {
+            Some(record) => record.total,
+            None if page.offset() > 0 => self.count_by_owner(owner_id, visibility).await?,
+            None => 0,
+        };
+        let items = records
+            .into_iter()
+            .map(|record| {
+                ExampleEntitySqlxRow {
+                    id: record.id,
+                    owner_id: record.owner_id,
+                    column_text: record.column_text,
+                    column_url: record.column_url,
+                    status: record.status,
+                    created_at: record.created_at,
+                    updated_at: record.updated_at,
+                    deleted_at: record.deleted_at,
+                    version: record.version,
+                }
+                .into_entity()
+            })
+            .collect();
+
+        Ok(PageResponse::new(items, total, page))
+    }
+
+    async fn find_by_owner_after(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+        cursor: Option<&PageCursor>,
+        limit: u32,
+    ) -> Result<CursorPage<ExampleEntity>, RepoError> {
+        let (active, deleted) = visibility_binds(visibility);
+        let cursor_created_at = cursor.map(PageCursor::created_at);
+        let cursor_id = cursor.map(|cursor| *cursor.id());
+
+        // Row comparison matches the Diesel predicate; NULL binds mean "first page".
+        let mut rows = sqlx::query_as!(
+            ExampleEntitySqlxRow,
+            "SELECT id, owner_id, column_text, column_url, status, created_at, updated_at, \
+             deleted_at, version FROM example_entities \
+             WHERE owner_id = $1 \
+               AND ((deleted_at IS NULL AND $2) OR (deleted_at IS NOT NULL AND $3)) \
+               AND ($4::timestamptz IS NULL OR (created_at, id) < ($4, $5::uuid)) \
+             ORDER BY created_at DESC, id DESC \
+             LIMIT $6",
+            owner_id,
+            active,
+            deleted,
+            cursor_created_at,
+            cursor_id,
+            i64::from(limit) + 1,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| map_sqlx_error("example_entity.find_by_owner_after", err))?;
+
+        let has_more = rows.len() > limit as usize;
+        rows.truncate(limit as usize);
+
+        let next_cursor = has_more
+            .then(|| rows.last().map(|row| PageCursor::new(row.created_at, row.id)))
+            .flatten();
+        let items = rows.into_iter().map(ExampleEntitySqlxRow::into_entity).collect();
+
+        Ok(CursorPage { items, next_cursor })
+    }
+
+    fn stream_by_owner(
+        &self,
+        owner_id: Uuid,
+        visibility: Visibility,
+    ) -> BoxStream<'static, Result<ExampleEntity, RepoError>> {
+        // PgPool is a cheap handle; the clone lets the stream outlive `&self`.
+        let pool = self.pool.clone();
+        let (active, deleted) = visibility_binds(visibility);
+
+        Box::pin(try_stream! {
+            let mut rows = sqlx::query_as!(
+                ExampleEntitySqlxRow,
+                "SELECT id, owner_id, column_text, column_url, status, created_at, updated_at, \
+                 deleted_at, version FROM example_entities \
+                 WHERE owner_id = $1 \
+                   AND ((deleted_at IS NULL AND $2) OR (deleted_at IS NOT NULL AND $3)) \
+                 ORDER BY created_at ASC, id ASC",
+                owner_id,
+                active,
+                deleted,
+            )
+            .fetch(&pool);
+
+            while let Some(row) = rows
+                .try_next()
+                .await
+                .map_err(|err| map_sqlx_error("example_entity.stream_by_owner", err))?
+            {
+                yield row.into_entity();
+            }
+        })
+    }
+
+    async fn find_matching(
+        &self,
+        spec: &Specification<ExampleEntityFilter>,
+        visibility: Visibility,
+    ) -> Result<Vec<ExampleEntity>, RepoError> {
+        let mut builder = matching_query(
+            "SELECT id, owner_id, column_text, column_url, status, created_at, updated_at, \
+             deleted_at, version",
+            spec,
+            visibility,
+        );
+        builder.push(" ORDER BY created_at DESC, id DESC");
+
+        let rows = builder
+            .build_query_as::<ExampleEntitySqlxRow>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|err| map_sqlx_error("example_entity.find_matching", err))?;
+
+        Ok(rows.into_iter().map(ExampleEntitySqlxRow::into_entity).collect())
+    }
+
+    async fn count_matching(
+        &self,
+        spec: &Specification<ExampleEntityFilter>,
+        visibility: Visibility,
+    ) -> Result<i64, RepoError> {
+        matching_query("SELECT COUNT(*)", spec, visibility)
+            .build_query_scalar::<i64>()
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|err| map_sqlx_error("example_entity.count_matching", err))
+    }
+
+    async fn update(&self, entity: &ExampleEntity) -> Result<(), RepoError> {
+        let result = sqlx::query!(
+            "UPDATE example_entities \
+             SET column_text = $3, column_url = $4, status = $5, updated_at = $6, \
+                 deleted_at = $7, version = version + 1 \
+             WHERE id = $1 AND version = $2",
+            entity.id().as_uuid(),
+            entity.version(),
+            entity.column_text().as_str(),
+            entity.column_url(),
+            entity.status().as_str(),
+            entity.updated_at(),
+            entity.deleted_at(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|err| map_sqlx_error("example_entity.update", err))?;
+
+        if result.rows_affected() == 0 {
+            return Err(self.stale_or_missing(entity.id()).await);
+        }
+
+        Ok(())
+    }
+
+    async fn upsert(&self, entity: &ExampleEntity) -> Result<UpsertOutcome, RepoError> {
+        // `xmax = 0` is true only for a freshly inserted tuple. No returned row means
+        // the `updated_at` guard kept a newer stored row.
+        let inserted = sqlx::query_scalar!(
+            r#"INSERT INTO example_entities
+               (id, owner_id, column_text, column_url, status, created_at, updated_at,
+                deleted_at, version)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+               ON CONFLICT (id) DO UPDATE
+               SET column_text = EXCLUDED.column_text,
+                   column_url = EXCLUDED.column_url,
+                   status = EXCLUDED.status,
+                   updated_at = EXCLUDED.updated_at,
+                   deleted_at = EXCLUDED.deleted_at,
+                   version = example_entities.version + 1
+               WHERE example_entities.updated_at < EXCLUDED.updated_at
+               RETURNING (xmax = 0) AS "inserted!""#,
+            entity.id().as_uuid(),
+            entity.owner_id(),
+            entity.column_text().as_str(),
+            entity.column_url(),
+            entity.status().as_str(),
+            entity.created_at(),
+            entity.updated_at(),
+            entity.deleted_at(),
+            entity.version(),
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| map_sqlx_error("example_entity.upsert", err))?;
+
+        Ok(match inserted {
+            Some(true) => UpsertOutcome::Inserted,
+            Some(false) => UpsertOutcome::Updated,
+            None => UpsertOutcome::Skipped,
+        })
+    }
+
+    async fn update_changed(
+        &self,
+        id: &ExampleEntityId,
+        changes: &ExampleEntityChanges,
+    ) -> Result<(), RepoError> {
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        // COALESCE keeps unchanged columns; `deleted_at` needs a flag because
+        // "set to NULL" and "leave alone" are both NULL as a bind.
+        let deleted_at = changes.deleted_at();
+        let result = sqlx::query!(
+            "UPDATE example_entities \
+             SET column_text = COALESCE($3, column_text), \
+                 column_url = COALESCE($4, column_url), \
+                 status = COALESCE($5, status), \
+                 updated_at = COALESCE($6, updated_at), \
+                 deleted_at = CASE WHEN $7 THEN $8 ELSE deleted_at END, \
+                 version = version + 1 \
+             WHERE id = $1 AND version = $2",
+            id.as_uuid(),
+            changes.expected_version(),
+            changes.column_text().map(ExampleEntityName::as_str),
+            changes.column_url(),
+            changes.status().map(ExampleEntityStatus::as_str),
+            changes.updated_at(),
+            deleted_at.is_some(),
+            deleted_at.flatten(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|err| map_sqlx_error("example_entity.update_changed", err))?;
+
+        if result.rows_affected() == 0 {
+            return Err(self.stale_or_missing(id).await);
+        }
+
+        Ok(())
+    }
+
+    async fn soft_delete(
+        &self,
+        id: &ExampleEntityId,
+        now: DateTime<Utc>,
+    ) -> Result<(), RepoError> {
+        let result = sqlx::query!(
+            "UPDATE example_entities \
+             SET status = $2, updated_at = $3, deleted_at = $3, version = version + 1 \
+             WHERE id = $1 AND deleted_at IS NULL",
+            id.as_uuid(),
+            ExampleEntityStatus::Inactive.as_str(),
+            now,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|err| map_sqlx_error("example_entity.soft_delete", err))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepoError::NotFound(format!("active example entity {id} not found")));
+        }
+
+        Ok(())
+    }
+
+    async fn restore(&self, id: &ExampleEntityId, now: DateTime<Utc>) -> Result<(), RepoError> {
+        let result = sqlx::query!(
+            "UPDATE example_entities \
+             SET status = $2, updated_at = $3, deleted_at = NULL, version = version + 1 \
+             WHERE id = $1 AND deleted_at IS NOT NULL",
+            id.as_uuid(),
+            ExampleEntityStatus::Active.as_str(),
+            now,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|err| map_sqlx_error("example_entity.restore", err))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepoError::NotFound(format!("deleted example entity {id} not found")));
+        }
+
+        Ok(())
+    }
+
+    async fn purge_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<usize, RepoError> {
+        let result = sqlx::query!(
+            "DELETE FROM example_entities WHERE id IN ( \
+                 SELECT id FROM example_entities \
+                 WHERE deleted_at < $1 ORDER BY deleted_at ASC LIMIT $2)",
+            cutoff,
+            limit,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|err| map_sqlx_error("example_entity.purge_deleted_before", err))?;
+
+        Ok(result.rows_affected() as usize)
+    }
+}
+
+// src/infra/db/database.rs
+// With `sqlx-repos`, the Postgres variant holds an sqlx pool instead:
+//
+// #[cfg(feature = "sqlx-repos")]
+// Postgres(sqlx::PgPool),
+//
+// #[cfg(feature = "sqlx-repos")]
+// let pool = PgPoolOptions::new().max_connections(max_size as u32).connect_lazy(url)
+//     .map_err(map_pool_error)?;
+//
+// #[cfg(feature = "sqlx-repos")]
+// Self::Postgres(pool) => Arc::new(ExampleSqlx::new(pool.clone())),
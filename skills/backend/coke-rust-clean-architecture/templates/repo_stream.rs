@@ -0,0 +1,116 @@
+// Template: streaming export. The repository streams rows with diesel-async's
+// `load_stream` (`ExampleRepository::stream_by_owner` in repo_trait.rs and
+// repo_diesel_impl.rs), the usecase maps entities to outputs, and the handler
+// writes one JSON object per line (NDJSON). Memory stays flat no matter how
+// many rows the export has.
+//
+// The stream holds a pooled connection for the whole download. Cap concurrent
+// exports (a semaphore or a dedicated route-level limit) so slow clients cannot
+// drain the pool.
+
+// src/usecases/example_feature/export.rs
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use futures::stream::{BoxStream, StreamExt};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::entities::ExampleEntity;
+use crate::domain::repositories::{ExampleRepository, Visibility};
+use crate::usecases::UsecaseError;
+
+pub struct ExportExampleEntityOutput {
+    pub id: Uuid,
+    pub column_text: String,
+    pub column_url: String,
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ExampleEntity> for ExportExampleEntityOutput {
+    fn from(entity: ExampleEntity) -> Self {
+        Self {
+            id: entity.id().into_uuid(),
+            column_text: entity.column_text().as_str().to_string(),
+            column_url: entity.column_url().to_string(),
+            status: entity.status().as_str().to_string(),
+            created_at: entity.created_at(),
+        }
+    }
+}
+
+pub struct ExportExampleEntitiesUseCase {
+    example_repo: Arc<dyn ExampleRepository>,
+}
+
+impl ExportExampleEntitiesUseCase {
+    pub fn new(example_repo: Arc<dyn ExampleRepository>) -> Self {
+        Self { example_repo }
+    }
+
+    pub fn execute(
+        &self,
+        owner_id: Uuid,
+    ) -> BoxStream<'static, Result<ExportExampleEntityOutput, UsecaseError>> {
+        self.example_repo
+            .stream_by_owner(owner_id, Visibility::ActiveOnly)
+            .map(|item| item.map(ExportExampleEntityOutput::from).map_err(UsecaseError::from))
+            .boxed()
+    }
+}
+
+// src/handlers/routers/public_api/example_action.rs
+use axum::body::{Body, Bytes};
+use axum::extract::State;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::{IntoResponse, Response};
+use tracing::error;
+
+use crate::handlers::app::state::AppState;
+use crate::handlers::shared::auth::AuthenticatedUser;
+use crate::infra::db::repositories::ExamplePostgres;
+
+#[derive(Debug, Serialize)]
+pub struct ExportExampleEntityLine {
+    pub id: Uuid,
+    pub column_text: String,
+    pub column_url: String,
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ExportExampleEntityOutput> for ExportExampleEntityLine {
+    fn from(output: ExportExampleEntityOutput) -> Self {
+        Self {
+            id: output.id,
+            column_text: output.column_text,
+            column_url: output.column_url,
+            status: output.status,
+            created_at: output.created_at,
+        }
+    }
+}
+
+// The 200 and headers are sent before the first row, so a failure mid-export
+// cannot become an ApiError. It is logged and the body ends early; clients
+// detect the truncated chunked response.
+pub async fn export_example_entities(
+    State(state): State<AppState>,
+    auth: AuthenticatedUser,
+) -> Response {
+    let example_repo: Arc<dyn ExampleRepository> =
+        Arc::new(ExamplePostgres::new(Arc::clone(&state.db_pool)));
+
+    let usecase = ExportExampleEntitiesUseCase::new(example_repo);
+    let lines = usecase.execute(auth.user_id).map(|item| {
+        let output = item.inspect_err(|err| error!(error = %err, "example entity export failed"))?;
+        let mut line = serde_json::to_vec(&ExportExampleEntityLine::from(output))
+            .map_err(|err| UsecaseError::Infra(err.into()))?;
+        line.push(b'\n');
+
+        Ok::<_, UsecaseError>(Bytes::from(line))
+    });
+
+    ([(CONTENT_TYPE, "application/x-ndjson")], Body::from_stream(lines)).into_response()
+}
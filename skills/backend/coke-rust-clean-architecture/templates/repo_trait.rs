@@ -2,21 +2,113 @@
 // and method names with project-specific names. Repository traits live in domain.
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
 use uuid::Uuid;
 
-use crate::domain::entities::ExampleEntity;
-use crate::domain::repositories::RepoError;
-use crate::domain::value_objects::ExampleEntityId;
+use crate::domain::entities::{ExampleEntity, ExampleEntityChanges};
+use crate::domain::repositories::{
+    CursorPage, ExampleEntityFilter, PageRequest, PageResponse, RepoError, Specification,
+    UpsertOutcome, Visibility,
+};
+use crate::domain::value_objects::{ExampleEntityId, PageCursor};
 
 #[async_trait]
 pub trait ExampleRepository: Send + Sync {
     async fn create(&self, entity: &ExampleEntity) -> Result<(), RepoError>;
 
+    // All-or-nothing; a failure names the chunk that failed. Returns rows inserted.
+    async fn create_many(&self, entities: &[ExampleEntity]) -> Result<usize, RepoError>;
+
     async fn find_by_id(&self, id: &ExampleEntityId) -> Result<Option<ExampleEntity>, RepoError>;
 
-    async fn find_by_owner(&self, owner_id: &Uuid) -> Result<Vec<ExampleEntity>, RepoError>;
+    // Results follow the order of `ids`; missing IDs are skipped.
+    async fn find_by_ids(&self, ids: &[ExampleEntityId]) -> Result<Vec<ExampleEntity>, RepoError>;
+
+    async fn find_by_owner(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+    ) -> Result<Vec<ExampleEntity>, RepoError>;
+
+    async fn count_by_owner(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+    ) -> Result<i64, RepoError>;
+
+    async fn find_by_owner_page(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+        page: &PageRequest,
+    ) -> Result<PageResponse<ExampleEntity>, RepoError>;
+
+    async fn find_by_owner_after(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+        cursor: Option<&PageCursor>,
+        limit: u32,
+    ) -> Result<CursorPage<ExampleEntity>, RepoError>;
+
+    // Rows arrive as Postgres sends them; nothing is buffered. The stream holds
+    // one pooled connection until it ends or is dropped.
+    fn stream_by_owner(
+        &self,
+        owner_id: Uuid,
+        visibility: Visibility,
+    ) -> BoxStream<'static, Result<ExampleEntity, RepoError>>;
+
+    async fn find_matching(
+        &self,
+        spec: &Specification<ExampleEntityFilter>,
+        visibility: Visibility,
+    ) -> Result<Vec<ExampleEntity>, RepoError>;
+
+    async fn count_matching(
+        &self,
+        spec: &Specification<ExampleEntityFilter>,
+        visibility: Visibility,
+    ) -> Result<i64, RepoError>;
 
     async fn update(&self, entity: &ExampleEntity) -> Result<(), RepoError>;
 
-    async fn delete(&self, id: &ExampleEntityId) -> Result<(), RepoError>;
+    // Insert or overwrite by ID for sync-style callers. An existing row with a newer or
+    // equal `updated_at` is left alone. Skips the version check; do not use for edits.
+    async fn upsert(&self, entity: &ExampleEntity) -> Result<UpsertOutcome, RepoError>;
+
+    async fn update_changed(
+        &self,
+        id: &ExampleEntityId,
+        changes: &ExampleEntityChanges,
+    ) -> Result<(), RepoError>;
+
+    // Sets `deleted_at` to `now`; the row stays for restore and audit. List and count methods
+    // hide it under `Visibility::ActiveOnly`. Returns `NotFound` when no active row matches.
+    // `now` comes from the usecase's `Clock`.
+    async fn soft_delete(
+        &self,
+        id: &ExampleEntityId,
+        now: DateTime<Utc>,
+    ) -> Result<(), RepoError>;
+
+    // Clears `deleted_at`. Returns `NotFound` when no soft-deleted row matches.
+    async fn restore(&self, id: &ExampleEntityId, now: DateTime<Utc>) -> Result<(), RepoError>;
+
+    // Hard-deletes at most `limit` rows soft-deleted before `cutoff`, oldest first.
+    async fn purge_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<usize, RepoError>;
+}
+
+// src/domain/repositories/upsert_outcome.rs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpsertOutcome {
+    Inserted,
+    Updated,
+    // The stored row was newer; nothing was written.
+    Skipped,
 }
@@ -0,0 +1,45 @@
+// Template: one `Visibility` parameter for every list/count repository method
+// on soft-deletable aggregates. Admin views and restore flows pass
+// `IncludeDeleted` or `DeletedOnly` instead of adding per-aggregate query
+// variants. `find_by_id` stays unfiltered so restore can load deleted rows.
+
+// src/domain/repositories/visibility.rs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Visibility {
+    #[default]
+    ActiveOnly,
+    IncludeDeleted,
+    DeletedOnly,
+}
+
+impl Visibility {
+    // Domain-side check for implementations that filter in Rust (in-memory repositories).
+    pub fn includes(&self, is_deleted: bool) -> bool {
+        match self {
+            Self::ActiveOnly => !is_deleted,
+            Self::DeletedOnly => is_deleted,
+            Self::IncludeDeleted => true,
+        }
+    }
+}
+
+// src/infra/db/repositories/visibility.rs
+use diesel::dsl::{IsNotNull, IsNull};
+use diesel::query_dsl::methods::FilterDsl;
+use diesel::ExpressionMethods;
+
+use crate::domain::repositories::Visibility;
+
+// Applies the filter on the table's `deleted_at` column. Box the query first
+// (`.into_boxed()`) so every branch returns the same type.
+pub(crate) fn filter_visibility<Q, C>(query: Q, deleted_at: C, visibility: Visibility) -> Q
+where
+    C: ExpressionMethods,
+    Q: FilterDsl<IsNull<C>, Output = Q> + FilterDsl<IsNotNull<C>, Output = Q>,
+{
+    match visibility {
+        Visibility::ActiveOnly => FilterDsl::filter(query, deleted_at.is_null()),
+        Visibility::DeletedOnly => FilterDsl::filter(query, deleted_at.is_not_null()),
+        Visibility::IncludeDeleted => query,
+    }
+}
@@ -0,0 +1,192 @@
+// Template: retention sweeper that hard-deletes soft-deleted rows once they are
+// older than the retention window. Each sweep deletes in bounded batches and
+// stops at a per-sweep cap, so a large backlog drains over several ticks
+// instead of holding locks and bloating WAL in one long run.
+//
+// Dependency direction:
+// spawner -> PurgeDeletedUseCase -> ExampleRepository::purge_deleted_before
+//
+// Index the filter so each batch is an index range scan:
+// CREATE INDEX example_entities_deleted_at_idx
+//     ON example_entities (deleted_at) WHERE deleted_at IS NOT NULL;
+
+// src/usecases/background/purge_deleted.rs
+use std::sync::Arc;
+
+use crate::domain::repositories::ExampleRepository;
+use crate::domain::services::Clock;
+use crate::domain::DomainError;
+use crate::usecases::UsecaseError;
+
+// Built through `new` so a zero batch size or cap, which would make a sweep
+// spin without deleting anything, fails at startup.
+#[derive(Debug, Clone, Copy)]
+pub struct RetentionPolicy {
+    retention: chrono::Duration,
+    batch_size: i64,
+    max_rows_per_sweep: usize,
+}
+
+impl RetentionPolicy {
+    pub fn new(
+        retention: chrono::Duration,
+        batch_size: i64,
+        max_rows_per_sweep: usize,
+    ) -> Result<Self, DomainError> {
+        if retention < chrono::Duration::zero() {
+            return Err(DomainError::invalid_field(
+                "retention",
+                "validation-out-of-range",
+            ));
+        }
+
+        if batch_size < 1 {
+            return Err(
+                DomainError::invalid_field("batch_size", "validation-out-of-range")
+                    .with_param("min", 1),
+            );
+        }
+
+        if max_rows_per_sweep == 0 {
+            return Err(DomainError::invalid_field(
+                "max_rows_per_sweep",
+                "validation-out-of-range",
+            )
+            .with_param("min", 1));
+        }
+
+        Ok(Self {
+            retention,
+            batch_size,
+            max_rows_per_sweep,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PurgeDeletedReport {
+    pub purged: usize,
+    pub batches: usize,
+    // True when the sweep stopped at `max_rows_per_sweep` with rows left over.
+    pub capped: bool,
+}
+
+pub struct PurgeDeletedUseCase {
+    example_repo: Arc<dyn ExampleRepository>,
+    clock: Arc<dyn Clock>,
+    policy: RetentionPolicy,
+}
+
+impl PurgeDeletedUseCase {
+    pub fn new(
+        example_repo: Arc<dyn ExampleRepository>,
+        clock: Arc<dyn Clock>,
+        policy: RetentionPolicy,
+    ) -> Self {
+        Self {
+            example_repo,
+            clock,
+            policy,
+        }
+    }
+
+    pub async fn execute(&self) -> Result<PurgeDeletedReport, UsecaseError> {
+        // One cutoff per sweep so rows deleted mid-sweep wait for the next tick.
+        let cutoff = self.clock.now() - self.policy.retention;
+        let mut report = PurgeDeletedReport::default();
+
+        while report.purged < self.policy.max_rows_per_sweep {
+            let remaining = (self.policy.max_rows_per_sweep - report.purged) as i64;
+            let limit = self.policy.batch_size.min(remaining);
+
+            let purged = self
+                .example_repo
+                .purge_deleted_before(cutoff, limit)
+                .await?;
+            report.purged += purged;
+            report.batches += 1;
+
+            if (purged as i64) < limit {
+                return Ok(report);
+            }
+        }
+
+        report.capped = true;
+        Ok(report)
+    }
+}
+
+// src/handlers/app/retention_sweeper.rs
+// Same spawner shape as background_job.rs. Each report is logged and exported
+// through the `metrics` facade; alert on `retention_sweeps_capped_total`
+// growing every tick, which means the cap is below the deletion rate.
+use std::sync::Arc;
+use std::time::Duration;
+
+use metrics::counter;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, warn};
+
+use crate::usecases::background::purge_deleted::{PurgeDeletedReport, PurgeDeletedUseCase};
+use crate::usecases::UsecaseError;
+
+pub fn spawn_retention_sweeper(
+    usecase: Arc<PurgeDeletedUseCase>,
+    cancel: CancellationToken,
+    interval_secs: u64,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        info!("retention sweeper started");
+
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => {
+                    info!("retention sweeper shutting down");
+                    break;
+                }
+                _ = interval.tick() => {
+                    let result = usecase.execute().await;
+                    record_sweep_metrics(&result);
+
+                    match result {
+                        Ok(report) if report.capped => warn!(
+                            purged = report.purged,
+                            batches = report.batches,
+                            "retention sweeper hit its per-sweep cap; backlog remains"
+                        ),
+                        Ok(report) => info!(
+                            purged = report.purged,
+                            batches = report.batches,
+                            "retention sweeper purged soft-deleted rows"
+                        ),
+                        Err(err) => error!(error = %err, "retention sweeper failed"),
+                    }
+                }
+            }
+        }
+    })
+}
+
+fn record_sweep_metrics(result: &Result<PurgeDeletedReport, UsecaseError>) {
+    match result {
+        Ok(report) => {
+            counter!("retention_rows_purged_total")
+                .increment(u64::try_from(report.purged).unwrap_or(u64::MAX));
+            if report.capped {
+                counter!("retention_sweeps_capped_total").increment(1);
+            }
+        }
+        Err(_) => counter!("retention_sweep_failures_total").increment(1),
+    }
+}
+
+// src/handlers/app/server.rs (startup)
+//
+// let purge_deleted = Arc::new(PurgeDeletedUseCase::new(
+//     Arc::clone(&example_repo),
+//     Arc::clone(&clock),
+//     RetentionPolicy::new(chrono::Duration::days(config.deleted_retention_days), 500, 50_000)?,
+// ));
+// spawn_retention_sweeper(purge_deleted, cancel.clone(), 300);
@@ -0,0 +1,286 @@
+// Template: `RetryingRepo<R>` wraps any repository and retries calls that fail
+// with `RepoError::is_transient()`: connection checkout failures, serialization
+// failures, and deadlocks. Delays come from the `RetryPolicy` value object
+// (value_object_retry_policy.rs). A shared `RetryBudget` caps retries to a
+// share of traffic, so an outage does not multiply database load.
+//
+// Usecases still receive `Arc<dyn ExampleRepository>`; only the handler that
+// builds the repository changes.
+//
+// Rules:
+// - Do not wrap repositories handed out by `UnitOfWork`. A serialization
+//   failure aborts the whole transaction, so retry the transaction instead.
+// - A replayed write fails loudly instead of applying twice: `create` returns
+//   `UniqueViolation` for the client-generated ID, and `update` returns
+//   `StaleVersion`. Keep both guarantees when adding write methods.
+// - `stream_by_owner` is not retried; rows may already have been sent.
+
+// src/infra/db/retrying/retrying_repo.rs
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use tracing::warn;
+
+use crate::domain::repositories::RepoError;
+use crate::domain::value_objects::RetryPolicy;
+
+// Every call adds `refill_per_call` tokens up to `max_tokens`; every retry
+// spends one. With 0.1, retries stay near 10% of calls once the bucket drains.
+pub struct RetryBudget {
+    tokens: Mutex<f64>,
+    max_tokens: f64,
+    refill_per_call: f64,
+}
+
+impl RetryBudget {
+    pub fn new(max_tokens: u32, refill_per_call: f64) -> Self {
+        Self {
+            tokens: Mutex::new(f64::from(max_tokens)),
+            max_tokens: f64::from(max_tokens),
+            refill_per_call,
+        }
+    }
+
+    fn record_call(&self) {
+        let mut tokens = self.tokens.lock().unwrap_or_else(PoisonError::into_inner);
+        *tokens = (*tokens + self.refill_per_call).min(self.max_tokens);
+    }
+
+    fn try_spend(&self) -> bool {
+        let mut tokens = self.tokens.lock().unwrap_or_else(PoisonError::into_inner);
+        if *tokens < 1.0 {
+            return false;
+        }
+        *tokens -= 1.0;
+        true
+    }
+}
+
+pub struct RetryingRepo<R: ?Sized> {
+    inner: Arc<R>,
+    policy: RetryPolicy,
+    budget: Arc<RetryBudget>,
+}
+
+impl<R: ?Sized> RetryingRepo<R> {
+    pub fn new(inner: Arc<R>, policy: RetryPolicy, budget: Arc<RetryBudget>) -> Self {
+        Self {
+            inner,
+            policy,
+            budget,
+        }
+    }
+
+    pub(crate) fn inner(&self) -> &R {
+        &self.inner
+    }
+
+    pub(crate) async fn retry<T, F, Fut>(&self, op: &'static str, call: F) -> Result<T, RepoError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, RepoError>>,
+    {
+        self.budget.record_call();
+        let mut attempt = 1;
+
+        loop {
+            match call().await {
+                Err(err)
+                    if err.is_transient()
+                        && self.policy.should_retry(attempt)
+                        && self.budget.try_spend() =>
+                {
+                    let delay = self.policy.next_delay(attempt);
+                    warn!(
+                        op,
+                        attempt,
+                        delay_ms = delay.as_millis() as u64,
+                        error = %err,
+                        "retrying transient repository error"
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+// src/infra/db/retrying/example_retrying.rs
+// One impl per repository trait; each method forwards through `retry` with the
+// same op name the inner implementation uses for errors.
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+use uuid::Uuid;
+
+use crate::domain::entities::{ExampleEntity, ExampleEntityChanges};
+use crate::domain::repositories::{
+    CursorPage, ExampleEntityFilter, ExampleRepository, PageRequest, PageResponse, Specification,
+    UpsertOutcome, Visibility,
+};
+use crate::domain::value_objects::{ExampleEntityId, PageCursor};
+use crate::infra::db::retrying::retrying_repo::RetryingRepo;
+
+#[async_trait]
+impl<R> ExampleRepository for RetryingRepo<R>
+where
+    R: ExampleRepository + ?Sized,
+{
+    async fn create(&self, entity: &ExampleEntity) -> Result<(), RepoError> {
+        self.retry("example_entity.create", || self.inner().create(entity)).await
+    }
+
+    async fn create_many(&self, entities: &[ExampleEntity]) -> Result<usize, RepoError> {
+        self.retry("example_entity.create_many", || self.inner().create_many(entities)).await
+    }
+
+    async fn find_by_id(&self, id: &ExampleEntityId) -> Result<Option<ExampleEntity>, RepoError> {
+        self.retry("example_entity.find_by_id", || self.inner().find_by_id(id)).await
+    }
+
+    async fn find_by_ids(&self, ids: &[ExampleEntityId]) -> Result<Vec<ExampleEntity>, RepoError> {
+        self.retry("example_entity.find_by_ids", || self.inner().find_by_ids(ids)).await
+    }
+
+    async fn find_by_owner(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+    ) -> Result<Vec<ExampleEntity>, RepoError> {
+        self.retry("example_entity.find_by_owner", || {
+            self.inner().find_by_owner(owner_id, visibility)
+        })
+        .await
+    }
+
+    async fn count_by_owner(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+    ) -> Result<i64, RepoError> {
+        self.retry("example_entity.count_by_owner", || {
+            self.inner().count_by_owner(owner_id, visibility)
+        })
+        .await
+    }
+
+    async fn find_by_owner_page(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+        page: &PageRequest,
+    ) -> Result<PageResponse<ExampleEntity>, RepoError> {
+        self.retry("example_entity.find_by_owner_page", || {
+            self.inner().find_by_owner_page(owner_id, visibility, page)
+        })
+        .await
+    }
+
+    async fn find_by_owner_after(
+        &self,
+        owner_id: &Uuid,
+        visibility: Visibility,
+        cursor: Option<&PageCursor>,
+        limit: u32,
+    ) -> Result<CursorPage<ExampleEntity>, RepoError> {
+        self.retry("example_entity.find_by_owner_after", || {
+            self.inner().find_by_owner_after(owner_id, visibility, cursor, limit)
+        })
+        .await
+    }
+
+    fn stream_by_owner(
+        &self,
+        owner_id: Uuid,
+        visibility: Visibility,
+    ) -> BoxStream<'static, Result<ExampleEntity, RepoError>> {
+        self.inner().stream_by_owner(owner_id, visibility)
+    }
+
+    async fn find_matching(
+        &self,
+        spec: &Specification<ExampleEntityFilter>,
+        visibility: Visibility,
+    ) -> Result<Vec<ExampleEntity>, RepoError> {
+        self.retry("example_entity.find_matching", || self.inner().find_matching(spec, visibility))
+            .await
+    }
+
+    async fn count_matching(
+        &self,
+        spec: &Specification<ExampleEntityFilter>,
+        visibility: Visibility,
+    ) -> Result<i64, RepoError> {
+        self.retry("example_entity.count_matching", || {
+            self.inner().count_matching(spec, visibility)
+        })
+        .await
+    }
+
+    async fn update(&self, entity: &ExampleEntity) -> Result<(), RepoError> {
+        self.retry("example_entity.update", || self.inner().update(entity)).await
+    }
+
+    async fn upsert(&self, entity: &ExampleEntity) -> Result<UpsertOutcome, RepoError> {
+        self.retry("example_entity.upsert", || self.inner().upsert(entity)).await
+    }
+
+    async fn update_changed(
+        &self,
+        id: &ExampleEntityId,
+        changes: &ExampleEntityChanges,
+    ) -> Result<(), RepoError> {
+        self.retry("example_entity.update_changed", || self.inner().update_changed(id, changes))
+            .await
+    }
+
+    async fn soft_delete(
+        &self,
+        id: &ExampleEntityId,
+        now: DateTime<Utc>,
+    ) -> Result<(), RepoError> {
+        self.retry("example_entity.soft_delete", || self.inner().soft_delete(id, now)).await
+    }
+
+    async fn restore(&self, id: &ExampleEntityId, now: DateTime<Utc>) -> Result<(), RepoError> {
+        self.retry("example_entity.restore", || self.inner().restore(id, now)).await
+    }
+
+    async fn purge_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<usize, RepoError> {
+        self.retry("example_entity.purge_deleted_before", || {
+            self.inner().purge_deleted_before(cutoff, limit)
+        })
+        .await
+    }
+}
+
+// src/handlers/app/state.rs (additions)
+// Policy and budget live in AppState because handlers build repositories per
+// request; a per-request budget would never run out.
+//
+// pub repo_retry_policy: RetryPolicy,
+// pub repo_retry_budget: Arc<RetryBudget>,
+//
+// repo_retry_policy: RetryPolicy::new(
+//     3,
+//     BackoffStrategy::ExponentialWithJitter,
+//     Duration::from_millis(20),
+//     Duration::from_millis(500),
+// )?,
+// repo_retry_budget: Arc::new(RetryBudget::new(100, 0.1)),
+
+// src/handlers/routers/public_api/example_action.rs
+// Only the repository construction changes:
+//
+// let repo = Arc::new(RetryingRepo::new(
+//     Arc::new(ExamplePostgres::new(Arc::clone(&state.db_pool))),
+//     state.repo_retry_policy,
+//     Arc::clone(&state.repo_retry_budget),
+// ));
+// let usecase = CreateExampleEntityUseCase::new(repo, Arc::clone(&state.clock));
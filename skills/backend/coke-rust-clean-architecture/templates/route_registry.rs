@@ -0,0 +1,195 @@
+// Template: route registry built on the `inventory` crate. Each handler leaf
+// file registers its own routes with `register_route!`, and routes.rs builds
+// the Router from everything registered. Adding a handler then touches only
+// its leaf file and the `pub mod` line in mod.rs.
+//
+// Cargo.toml: inventory = "0.3", tower = { version = "0.5", features = ["limit"] }
+//
+// - Paths are full paths including the surface prefix (`/api/...`). There is
+//   no `nest`; grep for the path to find its handler.
+// - Routes are added sorted by path and method, so the Router does not depend
+//   on link order.
+// - Every registration names a `StackName`. Routes are grouped by stack and
+//   each group is wrapped with `Stacks::wrap` from middleware_stack.rs, so
+//   registered routes get the same auth and rate limits as nested routers. One
+//   path may mix stacks across methods, e.g. a public GET and an authenticated PUT.
+// - Startup fails with one message listing every duplicate method + path and
+//   every path that differs only in parameter names, instead of Axum panicking
+//   on the first one.
+// - `inventory` only sees registrations in code that is linked. Keep handlers
+//   in the crate that calls `build_registered_routes`.
+// - Global layers (error localization, tracing, CORS) stay in routes.rs.
+//   `RouteMiddleware` is for layers that belong to a single route.
+
+// src/handlers/app/route_registry.rs
+use std::collections::BTreeMap;
+
+use axum::extract::DefaultBodyLimit;
+use axum::http::Method;
+use axum::routing::MethodRouter;
+use axum::Router;
+use tower::limit::ConcurrencyLimitLayer;
+
+use crate::handlers::app::middleware_stack::{StackName, Stacks};
+use crate::handlers::app::state::AppState;
+
+#[derive(Debug, Clone, Copy)]
+pub enum RouteMiddleware {
+    // Overrides Axum's default 2 MB request body limit.
+    BodyLimit(usize),
+    // Caps in-flight requests for this route across the process.
+    ConcurrencyLimit(usize),
+}
+
+impl RouteMiddleware {
+    fn apply(self, route: MethodRouter<AppState>) -> MethodRouter<AppState> {
+        match self {
+            Self::BodyLimit(bytes) => route.layer(DefaultBodyLimit::max(bytes)),
+            Self::ConcurrencyLimit(max) => route.layer(ConcurrencyLimitLayer::new(max)),
+        }
+    }
+}
+
+pub struct RouteRegistration {
+    pub method: Method,
+    pub path: &'static str,
+    pub stack: StackName,
+    pub router: fn() -> MethodRouter<AppState>,
+    pub middleware: &'static [RouteMiddleware],
+    pub module: &'static str,
+}
+
+inventory::collect!(RouteRegistration);
+
+// Registers one handler for one method and path. Put it in the handler's leaf
+// file, below the handler:
+//
+// crate::register_route!(
+//     POST "/api/example-entities" => create_example_entity,
+//     stack: Authenticated,
+// );
+// crate::register_route!(
+//     POST "/api/example-entities/import" => import_example_entities,
+//     stack: Authenticated,
+//     middleware: [RouteMiddleware::BodyLimit(20 * 1024 * 1024)],
+// );
+#[macro_export]
+macro_rules! register_route {
+    (
+        $method:ident $path:literal => $handler:path, stack: $stack:ident
+        $(, middleware: [$($middleware:expr),* $(,)?])? $(,)?
+    ) => {
+        ::inventory::submit! {
+            $crate::handlers::app::route_registry::RouteRegistration {
+                method: ::axum::http::Method::$method,
+                path: $path,
+                stack: $crate::handlers::app::middleware_stack::StackName::$stack,
+                router: || ::axum::routing::on(::axum::routing::MethodFilter::$method, $handler),
+                middleware: &[$($($middleware),*)?],
+                module: module_path!(),
+            }
+        }
+    };
+}
+
+pub fn build_registered_routes(stacks: &Stacks) -> Router<AppState> {
+    let mut registrations: Vec<&RouteRegistration> =
+        inventory::iter::<RouteRegistration>.into_iter().collect();
+    registrations.sort_by(|a, b| (a.path, a.method.as_str()).cmp(&(b.path, b.method.as_str())));
+
+    let conflicts = route_conflicts(&registrations);
+    assert!(conflicts.is_empty(), "conflicting routes:\n{}", conflicts.join("\n"));
+
+    let mut by_stack: BTreeMap<StackName, BTreeMap<&'static str, MethodRouter<AppState>>> =
+        BTreeMap::new();
+    for registration in registrations {
+        let route = registration
+            .middleware
+            .iter()
+            .fold((registration.router)(), |route, middleware| middleware.apply(route));
+
+        let routes = by_stack.entry(registration.stack).or_default();
+        let merged = match routes.remove(registration.path) {
+            Some(existing) => existing.merge(route),
+            None => route,
+        };
+        routes.insert(registration.path, merged);
+    }
+
+    // Axum merges method routers that share a path, so a path split across
+    // stacks still serves every method.
+    by_stack.into_iter().fold(Router::new(), |router, (stack, routes)| {
+        let group =
+            routes.into_iter().fold(Router::new(), |group, (path, route)| group.route(path, route));
+        router.merge(stacks.wrap(stack, group))
+    })
+}
+
+// Expects `registrations` sorted by path and method.
+fn route_conflicts(registrations: &[&RouteRegistration]) -> Vec<String> {
+    let mut by_shape: BTreeMap<String, Vec<&RouteRegistration>> = BTreeMap::new();
+    for registration in registrations {
+        by_shape.entry(path_shape(registration.path)).or_default().push(registration);
+    }
+
+    let mut conflicts = Vec::new();
+    for group in by_shape.values() {
+        let describe = |registration: &&RouteRegistration| {
+            format!("{} {} ({})", registration.method, registration.path, registration.module)
+        };
+
+        if group.iter().any(|registration| registration.path != group[0].path) {
+            let routes: Vec<String> = group.iter().map(describe).collect();
+            conflicts.push(format!("parameter names differ: {}", routes.join(", ")));
+            continue;
+        }
+
+        for (index, registration) in group.iter().enumerate().skip(1) {
+            if registration.method == group[index - 1].method {
+                let routes = [describe(&group[index - 1]), describe(registration)];
+                conflicts.push(format!("registered twice: {}", routes.join(", ")));
+            }
+        }
+    }
+    conflicts
+}
+
+// `/api/things/{id}` and `/api/things/{thing_id}` have the same shape.
+fn path_shape(path: &str) -> String {
+    path.split('/')
+        .map(|segment| if segment.starts_with(['{', ':']) { "{}" } else { segment })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+// src/handlers/app/routes.rs
+use std::sync::Arc;
+
+use axum::middleware;
+
+use crate::handlers::app::middleware::localize_errors;
+use crate::handlers::app::middleware_stack::Stacks;
+use crate::handlers::app::route_registry::build_registered_routes;
+
+pub fn build_routes(state: AppState) -> Router {
+    let stacks = Stacks::new(&state);
+
+    build_registered_routes(&stacks)
+        .layer(middleware::from_fn_with_state(
+            Arc::clone(&state.messages),
+            localize_errors,
+        ))
+        .with_state(state)
+}
+
+// src/handlers/routers/public_api/example_action.rs
+// The handler from handler_axum.rs, unchanged, followed by its registration.
+// The feature router file (`example_feature.rs` with `router()`) is no longer
+// needed.
+//
+// pub async fn create_example_entity(...) -> Result<impl IntoResponse, ApiError> { ... }
+//
+// crate::register_route!(
+//     POST "/api/example-entities" => create_example_entity,
+//     stack: Authenticated,
+// );
@@ -0,0 +1,466 @@
+// Template: aggregate scaffolder for projects that add aggregates often.
+//
+// cargo run --features scaffold --bin scaffold -- \
+//     aggregate Invoice --fields "number:string, amount:money" [--plural invoices] [--dry-run]
+//
+// It renders the project's own scaffold templates in `scaffold/templates/`.
+// Copy them from this skill's `templates/scaffold/` directory. They are cut-down
+// forms of value_object_id_macro.rs, domain_entity.rs, repo_trait.rs,
+// repo_diesel_impl.rs, repo_in_memory.rs, usecase.rs, handler_axum.rs, and the
+// TDD repository test; when one of those changes, change its `.j2` as well.
+// The generator holds no Rust code of its own.
+//
+// Output is a starting point, not a finished feature: it compiles once
+// `diesel migration run` regenerates schema.rs, then the TDD loop takes over.
+// It never overwrites a generated file. A run that fails partway undoes its
+// writes, including mod.rs declarations, before it exits.
+//
+// Cargo.toml:
+//
+// [[bin]]
+// name = "scaffold"
+// required-features = ["scaffold"]
+//
+// [features]
+// scaffold = ["dep:minijinja"]
+//
+// minijinja needs its `loader` feature for `path_loader`.
+
+// src/bin/scaffold.rs
+use std::fmt;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use chrono::Utc;
+use minijinja::{context, Environment, UndefinedBehavior};
+use serde::Serialize;
+
+const TEMPLATE_DIR: &str = "scaffold/templates";
+
+// Template file -> output path. `{snake}`, `{plural}`, and `{migration}` are
+// filled from the aggregate name before the file is rendered.
+const FILES: &[(&str, &str)] = &[
+    ("migration_up.sql.j2", "migrations/{migration}/up.sql"),
+    ("migration_down.sql.j2", "migrations/{migration}/down.sql"),
+    ("id.rs.j2", "src/domain/value_objects/ids/{snake}_id.rs"),
+    ("entity.rs.j2", "src/domain/entities/{snake}.rs"),
+    ("repository.rs.j2", "src/domain/repositories/{snake}_repository.rs"),
+    ("postgres.rs.j2", "src/infra/db/repositories/{snake}_postgres.rs"),
+    ("in_memory.rs.j2", "src/infra/memory/{snake}_in_memory.rs"),
+    ("usecase_create.rs.j2", "src/usecases/{snake}/create.rs"),
+    ("usecase_get.rs.j2", "src/usecases/{snake}/get.rs"),
+    ("usecase_update.rs.j2", "src/usecases/{snake}/update.rs"),
+    ("usecase_delete.rs.j2", "src/usecases/{snake}/delete.rs"),
+    ("handler.rs.j2", "src/handlers/routers/public_api/{snake}.rs"),
+    ("repository_test.rs.j2", "tests/repositories/{snake}_postgres_test.rs"),
+];
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum FieldKind {
+    String,
+    Text,
+    Int,
+    Bool,
+    Uuid,
+    Timestamp,
+    Money,
+}
+
+impl FieldKind {
+    fn parse(value: &str) -> Result<Self, ScaffoldError> {
+        match value {
+            "string" => Ok(Self::String),
+            "text" => Ok(Self::Text),
+            "int" => Ok(Self::Int),
+            "bool" => Ok(Self::Bool),
+            "uuid" => Ok(Self::Uuid),
+            "timestamp" => Ok(Self::Timestamp),
+            "money" => Ok(Self::Money),
+            other => Err(ScaffoldError::Usage(format!("unknown field type `{other}`"))),
+        }
+    }
+
+    // Templates read these instead of matching on the kind themselves.
+    fn rust_type(self) -> &'static str {
+        match self {
+            Self::String | Self::Text => "String",
+            Self::Int => "i64",
+            Self::Bool => "bool",
+            Self::Uuid => "Uuid",
+            Self::Timestamp => "DateTime<Utc>",
+            Self::Money => "Money",
+        }
+    }
+
+    fn sql_type(self) -> &'static str {
+        match self {
+            Self::String => "VARCHAR(255)",
+            Self::Text => "TEXT",
+            Self::Int => "BIGINT",
+            Self::Bool => "BOOLEAN",
+            Self::Uuid => "UUID",
+            Self::Timestamp => "TIMESTAMPTZ",
+            // The `{name}_minor` column; templates add `{name}_currency CHAR(3)`.
+            Self::Money => "BIGINT",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Field {
+    name: String,
+    kind: FieldKind,
+    rust_type: &'static str,
+    sql_type: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct Names {
+    // Invoice, invoice, invoices
+    pascal: String,
+    snake: String,
+    plural: String,
+}
+
+#[derive(Debug)]
+enum ScaffoldError {
+    Usage(String),
+    Exists(Vec<PathBuf>),
+    Template(minijinja::Error),
+    Io(PathBuf, std::io::Error),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Usage(message) => write!(f, "{message}\n\n{USAGE}"),
+            Self::Exists(paths) => {
+                writeln!(f, "refusing to overwrite existing files:")?;
+                for path in paths {
+                    writeln!(f, "  {}", path.display())?;
+                }
+                Ok(())
+            }
+            Self::Template(err) => write!(f, "template error: {err:#}"),
+            Self::Io(path, err) => write!(f, "{}: {err}", path.display()),
+        }
+    }
+}
+
+const USAGE: &str = "usage: scaffold aggregate <PascalName> --fields \"name:type, ...\" \
+                     [--plural <snake_plural>] [--dry-run]\n\
+                     types: string, text, int, bool, uuid, timestamp, money";
+
+struct Args {
+    names: Names,
+    fields: Vec<Field>,
+    dry_run: bool,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, ScaffoldError> {
+    if args.next().as_deref() != Some("aggregate") {
+        return Err(ScaffoldError::Usage("expected `aggregate`".to_string()));
+    }
+    let pascal = args
+        .next()
+        .filter(|name| is_pascal_case(name))
+        .ok_or_else(|| ScaffoldError::Usage("expected a PascalCase aggregate name".to_string()))?;
+
+    let mut fields = None;
+    let mut plural = None;
+    let mut dry_run = false;
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "--fields" => fields = args.next(),
+            "--plural" => plural = args.next(),
+            "--dry-run" => dry_run = true,
+            other => return Err(ScaffoldError::Usage(format!("unknown flag `{other}`"))),
+        }
+    }
+
+    let fields = fields
+        .ok_or_else(|| ScaffoldError::Usage("--fields is required".to_string()))?
+        .split(',')
+        .map(str::trim)
+        .filter(|spec| !spec.is_empty())
+        .map(parse_field)
+        .collect::<Result<Vec<_>, _>>()?;
+    if fields.is_empty() {
+        return Err(ScaffoldError::Usage("--fields needs at least one field".to_string()));
+    }
+
+    let snake = to_snake_case(&pascal);
+    check_snake_ident("aggregate name", &snake)?;
+    // Naive English plural; pass --plural for anything irregular.
+    let plural = plural.unwrap_or_else(|| format!("{snake}s"));
+    check_snake_ident("--plural", &plural)?;
+
+    Ok(Args {
+        names: Names {
+            pascal,
+            snake,
+            plural,
+        },
+        fields,
+        dry_run,
+    })
+}
+
+fn parse_field(spec: &str) -> Result<Field, ScaffoldError> {
+    let (name, kind) = spec
+        .split_once(':')
+        .ok_or_else(|| ScaffoldError::Usage(format!("field `{spec}` must be name:type")))?;
+    let name = name.trim();
+    check_snake_ident("field name", name)?;
+    let kind = FieldKind::parse(kind.trim())?;
+
+    Ok(Field {
+        name: name.to_string(),
+        kind,
+        rust_type: kind.rust_type(),
+        sql_type: kind.sql_type(),
+    })
+}
+
+// Strict and reserved keywords of the 2021 and 2024 editions. Rejected rather
+// than emitted as `r#type`, because the name also becomes a module, column, and
+// JSON key, and `self`, `super`, and `crate` cannot be raw identifiers at all.
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+// `Invoice`, `LineItem2`: ASCII letters and digits, starting uppercase. Anything
+// else would not survive the round trip through snake_case and back.
+fn is_pascal_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric())
+        && name != "Self"
+}
+
+fn check_snake_ident(what: &str, name: &str) -> Result<(), ScaffoldError> {
+    let valid = name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !valid {
+        return Err(ScaffoldError::Usage(format!("{what} `{name}` must be snake_case")));
+    }
+    if RUST_KEYWORDS.contains(&name) {
+        return Err(ScaffoldError::Usage(format!("{what} `{name}` is a Rust keyword")));
+    }
+
+    Ok(())
+}
+
+fn to_snake_case(pascal: &str) -> String {
+    let mut snake = String::with_capacity(pascal.len() + 4);
+    for (index, c) in pascal.chars().enumerate() {
+        if c.is_ascii_uppercase() && index > 0 {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
+
+fn output_path(pattern: &str, names: &Names, migration: &str) -> PathBuf {
+    PathBuf::from(
+        pattern
+            .replace("{snake}", &names.snake)
+            .replace("{plural}", &names.plural)
+            .replace("{migration}", migration),
+    )
+}
+
+// Every file and directory a run writes, so a failure partway can be undone.
+#[derive(Default)]
+struct Journal {
+    // The contents before this run; `None` when the run created the file.
+    files: Vec<(PathBuf, Option<String>)>,
+    dirs: Vec<PathBuf>,
+}
+
+impl Journal {
+    fn create_dir_all(&mut self, dir: &Path) -> Result<(), ScaffoldError> {
+        // Innermost first, the order `roll_back` removes them in.
+        let missing: Vec<PathBuf> = dir
+            .ancestors()
+            .take_while(|ancestor| !ancestor.as_os_str().is_empty() && !ancestor.exists())
+            .map(Path::to_path_buf)
+            .collect();
+
+        fs::create_dir_all(dir).map_err(|err| ScaffoldError::Io(dir.into(), err))?;
+        self.dirs.extend(missing.into_iter().rev());
+        Ok(())
+    }
+
+    // Recorded before writing, so a write that fails halfway is undone too.
+    fn write(&mut self, path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+        let previous = match fs::read_to_string(path) {
+            Ok(previous) => Some(previous),
+            Err(err) if err.kind() == ErrorKind::NotFound => None,
+            Err(err) => return Err(ScaffoldError::Io(path.into(), err)),
+        };
+        self.files.push((path.to_path_buf(), previous));
+
+        fs::write(path, contents).map_err(|err| ScaffoldError::Io(path.into(), err))
+    }
+
+    // Best effort, newest write first. Returns the paths it could not restore.
+    fn roll_back(self) -> Vec<PathBuf> {
+        let mut stuck = Vec::new();
+
+        for (path, previous) in self.files.into_iter().rev() {
+            let restored = match previous {
+                Some(contents) => fs::write(&path, contents),
+                None => fs::remove_file(&path).or_else(|err| match err.kind() {
+                    ErrorKind::NotFound => Ok(()),
+                    _ => Err(err),
+                }),
+            };
+            if restored.is_err() {
+                stuck.push(path);
+            }
+        }
+        for dir in self.dirs.into_iter().rev() {
+            if fs::remove_dir(&dir).is_err() {
+                stuck.push(dir);
+            }
+        }
+
+        stuck
+    }
+}
+
+// Appends `pub mod {name};` to the parent mod.rs. A mod.rs created here is new,
+// so its own directory is declared one level up as well. Stops at `src/`,
+// whose root module is lib.rs or main.rs.
+fn declare_module(path: &Path, journal: &mut Journal) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let mut updated = Vec::new();
+    let mut path = path.to_path_buf();
+
+    while let (Some(parent), Some(stem)) = (path.parent(), path.file_stem()) {
+        if !parent.starts_with("src") || parent == Path::new("src") {
+            break;
+        }
+
+        let mod_rs = parent.join("mod.rs");
+        let created = !mod_rs.exists();
+        let mut contents = fs::read_to_string(&mod_rs).unwrap_or_default();
+        let declaration = format!("pub mod {};", stem.to_string_lossy());
+
+        if contents.lines().any(|line| line.trim() == declaration) {
+            break;
+        }
+        if !contents.is_empty() && !contents.ends_with('\n') {
+            contents.push('\n');
+        }
+        contents.push_str(&declaration);
+        contents.push('\n');
+        journal.write(&mod_rs, &contents)?;
+        updated.push(mod_rs);
+
+        if !created {
+            break;
+        }
+        path = parent.to_path_buf();
+    }
+
+    Ok(updated)
+}
+
+fn write_aggregate(
+    rendered: &[(PathBuf, String)],
+    journal: &mut Journal,
+) -> Result<(), ScaffoldError> {
+    for (path, source) in rendered {
+        println!("create  {}", path.display());
+        if let Some(parent) = path.parent() {
+            journal.create_dir_all(parent)?;
+        }
+        journal.write(path, source)?;
+        for mod_rs in declare_module(path, journal)? {
+            println!("update  {}", mod_rs.display());
+        }
+    }
+
+    Ok(())
+}
+
+fn run(args: Args) -> Result<(), ScaffoldError> {
+    let mut env = Environment::new();
+    // A typo in a template must fail the run, not emit empty code.
+    env.set_undefined_behavior(UndefinedBehavior::Strict);
+    env.set_loader(minijinja::path_loader(TEMPLATE_DIR));
+
+    let migration = format!(
+        "{}_create_{}",
+        Utc::now().format("%Y-%m-%d-%H%M%S"),
+        args.names.plural
+    );
+    let targets: Vec<(&str, PathBuf)> = FILES
+        .iter()
+        .map(|(template, pattern)| (*template, output_path(pattern, &args.names, &migration)))
+        .collect();
+
+    // Check and render everything before the first write; `Journal` covers the rest.
+    let existing: Vec<PathBuf> = targets
+        .iter()
+        .filter(|(_, path)| path.exists())
+        .map(|(_, path)| path.clone())
+        .collect();
+    if !existing.is_empty() {
+        return Err(ScaffoldError::Exists(existing));
+    }
+
+    let ctx = context! {
+        names => &args.names,
+        fields => &args.fields,
+        has_money => args.fields.iter().any(|field| matches!(field.kind, FieldKind::Money)),
+        crate_name => env!("CARGO_PKG_NAME").replace('-', "_"),
+    };
+    let mut rendered = Vec::with_capacity(targets.len());
+    for (template, path) in targets {
+        let source = env
+            .get_template(template)
+            .and_then(|template| template.render(&ctx))
+            .map_err(ScaffoldError::Template)?;
+        rendered.push((path, source));
+    }
+
+    if args.dry_run {
+        for (path, _) in &rendered {
+            println!("create  {}", path.display());
+        }
+    } else {
+        let mut journal = Journal::default();
+        if let Err(err) = write_aggregate(&rendered, &mut journal) {
+            eprintln!("scaffold: undoing the files written so far");
+            for path in journal.roll_back() {
+                eprintln!("scaffold: could not undo {}", path.display());
+            }
+            return Err(err);
+        }
+    }
+
+    println!(
+        "\nnext: diesel migration run, cargo fmt, add the router to handlers/app/routes.rs, \
+         cargo test"
+    );
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match parse_args(std::env::args().skip(1)).and_then(run) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("scaffold: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,149 @@
+// Template: `run_serializable_tx` runs a closure in a SERIALIZABLE transaction
+// and reruns it when Postgres aborts it with a serialization failure (40001) or
+// deadlock (40P01). After `max_attempts` it returns `RepoError::TxConflict`,
+// which usecases turn into a 409.
+//
+// Use it for check-then-write rules that must hold under concurrency, such as
+// "at most N active entities per owner". A plain READ COMMITTED transaction
+// lets two requests both see N-1 rows and both insert.
+//
+// - The closure runs once per attempt. Keep it free of side effects outside
+//   the transaction: no HTTP calls, no events except through the outbox.
+// - Each attempt takes a fresh pooled connection; the failed one rolls back.
+// - Do not wrap the repository method in `RetryingRepo` as well; the retries
+//   would multiply.
+// - A trait method such as `create_if_under_limit` below needs an equivalent in
+//   every implementation; the in-memory one checks and inserts under its lock.
+
+// src/infra/db/serializable_tx.rs
+use diesel::result::Error as DieselError;
+use diesel_async::scoped_futures::{ScopedBoxFuture, ScopedFutureExt};
+use diesel_async::AsyncPgConnection;
+use futures::TryFutureExt;
+use tracing::debug;
+
+use crate::domain::repositories::RepoError;
+use crate::infra::db::postgres_connection::PgPool;
+use crate::infra::db::repositories::error_mapping::{map_diesel_error, map_pool_error};
+
+// BEGIN and COMMIT fail with DieselError; the closure fails with RepoError.
+enum SerializableTxError {
+    Diesel(DieselError),
+    Repo(RepoError),
+}
+
+impl From<DieselError> for SerializableTxError {
+    fn from(err: DieselError) -> Self {
+        Self::Diesel(err)
+    }
+}
+
+pub(crate) async fn run_serializable_tx<'a, T, F>(
+    pool: &PgPool,
+    op: &'static str,
+    max_attempts: u32,
+    body: F,
+) -> Result<T, RepoError>
+where
+    F: for<'r> Fn(&'r mut AsyncPgConnection) -> ScopedBoxFuture<'a, 'r, Result<T, RepoError>>
+        + Send
+        + Sync
+        + 'a,
+    T: Send + 'a,
+{
+    let mut attempt = 1;
+
+    loop {
+        let mut conn = pool.get().await.map_err(map_pool_error)?;
+        let result = conn
+            .build_transaction()
+            .serializable()
+            .run(|conn| body(conn).map_err(SerializableTxError::Repo).scope_boxed())
+            .await;
+
+        let err = match result {
+            Ok(value) => return Ok(value),
+            Err(SerializableTxError::Repo(err)) => err,
+            Err(SerializableTxError::Diesel(err)) => map_diesel_error(op, err),
+        };
+
+        // Only a rolled-back conflict is safe to rerun.
+        if !matches!(err, RepoError::Transient { .. }) {
+            return Err(err);
+        }
+        if attempt >= max_attempts {
+            return Err(RepoError::TxConflict {
+                op,
+                attempts: attempt,
+            });
+        }
+
+        debug!(op, attempt, "serialization conflict; rerunning transaction");
+        attempt += 1;
+    }
+}
+
+// src/domain/repositories/example_repository.rs (addition)
+//
+// // Inserts only while the owner has fewer than `max_active` active entities.
+// // Returns false, writing nothing, when the owner is at the limit.
+// async fn create_if_under_limit(
+//     &self,
+//     entity: &ExampleEntity,
+//     max_active: i64,
+// ) -> Result<bool, RepoError>;
+
+// src/infra/db/repositories/example_postgres.rs (addition)
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+
+use crate::domain::entities::ExampleEntity;
+use crate::infra::db::schema::example_entities;
+use crate::infra::db::serializable_tx::run_serializable_tx;
+
+impl ExamplePostgres {
+    // Conflicts are rare; when three attempts fail, the client retries.
+    const SERIALIZABLE_ATTEMPTS: u32 = 3;
+}
+
+// Inside `impl ExampleRepository for ExamplePostgres`:
+async fn create_if_under_limit(
+    &self,
+    entity: &ExampleEntity,
+    max_active: i64,
+) -> Result<bool, RepoError> {
+    let new_row = NewExampleEntityRow::from_entity(entity);
+    let owner_id = *entity.owner_id();
+
+    run_serializable_tx(
+        &self.pool,
+        "example_entity.create_if_under_limit",
+        Self::SERIALIZABLE_ATTEMPTS,
+        |conn| {
+            let new_row = &new_row;
+            async move {
+                let active = example_entities::table
+                    .filter(example_entities::owner_id.eq(owner_id))
+                    .filter(example_entities::deleted_at.is_null())
+                    .count()
+                    .get_result::<i64>(conn)
+                    .await
+                    .map_err(|err| map_diesel_error("example_entity.count_active", err))?;
+
+                if active >= max_active {
+                    return Ok(false);
+                }
+
+                diesel::insert_into(example_entities::table)
+                    .values(new_row)
+                    .execute(conn)
+                    .await
+                    .map_err(|err| map_diesel_error("example_entity.create_if_under_limit", err))?;
+
+                Ok(true)
+            }
+            .scope_boxed()
+        },
+    )
+    .await
+}
@@ -0,0 +1,242 @@
+// Template: text/HTML rendering port for emails, status pages, and outbound
+// payloads. Built-in templates compile with askama, so a missing field fails the
+// build. User-supplied templates run in a restricted minijinja environment with
+// size and fuel limits. Usecases see only the port and a JSON context.
+//
+// Dependency direction:
+// usecase -> TemplateRenderer (domain port)
+// infra askama / minijinja adapters -> domain port
+
+// src/domain/services/template_renderer.rs
+use serde_json::Value;
+
+use crate::domain::services::ServiceError;
+
+#[derive(Debug, Clone, Copy)]
+pub enum TemplateSource<'a> {
+    // A template shipped with the binary, looked up by name.
+    Builtin(&'a str),
+    // Template text stored by a user, such as a per-resource payload template.
+    User(&'a str),
+}
+
+pub trait TemplateRenderer: Send + Sync {
+    // A bad user template or context is `InvalidRequest`; a built-in template
+    // that fails to render is `Unavailable`.
+    fn render(&self, source: TemplateSource<'_>, context: &Value) -> Result<String, ServiceError>;
+}
+
+// templates/emails/example_notification.html (askama looks under `templates/`)
+//
+// <p>Hello {{ recipient_name }},</p>
+// <p>{{ entity_name }} changed status to {{ status }}.</p>
+
+// src/infra/services/askama_renderer.rs
+use askama::Template;
+use serde::Deserialize;
+
+#[derive(Template, Deserialize)]
+#[template(path = "emails/example_notification.html")]
+struct ExampleNotificationEmail {
+    recipient_name: String,
+    entity_name: String,
+    status: String,
+}
+
+pub struct AskamaRenderer;
+
+impl AskamaRenderer {
+    // One arm per built-in template. The context must deserialize into the
+    // template struct, so a usecase passing the wrong shape fails loudly.
+    fn render_builtin(name: &str, context: &Value) -> Result<String, ServiceError> {
+        match name {
+            "example_notification" => render_as::<ExampleNotificationEmail>(context),
+            _ => Err(ServiceError::InvalidRequest(format!("unknown template: {name}"))),
+        }
+    }
+}
+
+impl TemplateRenderer for AskamaRenderer {
+    fn render(&self, source: TemplateSource<'_>, context: &Value) -> Result<String, ServiceError> {
+        match source {
+            TemplateSource::Builtin(name) => Self::render_builtin(name, context),
+            TemplateSource::User(_) => Err(ServiceError::InvalidRequest(
+                "askama renders built-in templates only".to_string(),
+            )),
+        }
+    }
+}
+
+fn render_as<T>(context: &Value) -> Result<String, ServiceError>
+where
+    T: Template + for<'de> Deserialize<'de>,
+{
+    let template = T::deserialize(context)
+        .map_err(|err| ServiceError::InvalidRequest(format!("template context: {err}")))?;
+
+    template
+        .render()
+        .map_err(|err| ServiceError::Unavailable(format!("template render: {err}")))
+}
+
+// src/infra/services/minijinja_renderer.rs
+// Needs minijinja's `fuel` feature. The environment has no loader, so user
+// templates cannot `include`, `import`, or `extends` anything.
+use std::io;
+
+use minijinja::{Environment, UndefinedBehavior};
+
+#[derive(Debug, Clone, Copy)]
+pub struct SandboxLimits {
+    pub max_source_bytes: usize,
+    // Enforced while rendering; the render stops at the first write past it.
+    pub max_output_bytes: usize,
+    // Roughly one unit per executed instruction; bounds loops and recursion.
+    pub fuel: u64,
+}
+
+impl Default for SandboxLimits {
+    fn default() -> Self {
+        Self {
+            max_source_bytes: 16 * 1024,
+            max_output_bytes: 256 * 1024,
+            fuel: 50_000,
+        }
+    }
+}
+
+pub struct MinijinjaRenderer {
+    env: Environment<'static>,
+    limits: SandboxLimits,
+}
+
+impl MinijinjaRenderer {
+    pub fn new(limits: SandboxLimits) -> Self {
+        let mut env = Environment::new();
+        env.set_fuel(Some(limits.fuel));
+        env.set_undefined_behavior(UndefinedBehavior::Strict);
+
+        Self { env, limits }
+    }
+
+    // Compiles without rendering; call when a user saves a template.
+    pub fn validate(&self, source: &str) -> Result<(), ServiceError> {
+        self.check_source(source)?;
+        self.env
+            .template_from_str(source)
+            .map(|_| ())
+            .map_err(|err| ServiceError::InvalidRequest(format!("template syntax: {err}")))
+    }
+
+    fn check_source(&self, source: &str) -> Result<(), ServiceError> {
+        if source.len() > self.limits.max_source_bytes {
+            return Err(ServiceError::InvalidRequest("template too large".to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+impl TemplateRenderer for MinijinjaRenderer {
+    fn render(&self, source: TemplateSource<'_>, context: &Value) -> Result<String, ServiceError> {
+        let TemplateSource::User(source) = source else {
+            return Err(ServiceError::InvalidRequest(
+                "minijinja renders user templates only".to_string(),
+            ));
+        };
+        self.check_source(source)?;
+
+        let template = self
+            .env
+            .template_from_str(source)
+            .map_err(|err| ServiceError::InvalidRequest(format!("template syntax: {err}")))?;
+
+        let mut output = LimitedWriter::new(self.limits.max_output_bytes);
+        let rendered = template.render_to_write(context, &mut output);
+
+        if output.exceeded {
+            return Err(ServiceError::InvalidRequest("template output too large".to_string()));
+        }
+        rendered.map_err(|err| ServiceError::InvalidRequest(format!("template render: {err}")))?;
+
+        String::from_utf8(output.buf)
+            .map_err(|err| ServiceError::InvalidRequest(format!("template output: {err}")))
+    }
+}
+
+// Fails the write that would pass the limit, which aborts the render there, so
+// a template that expands a large loop never buffers more than the limit.
+struct LimitedWriter {
+    buf: Vec<u8>,
+    limit: usize,
+    exceeded: bool,
+}
+
+impl LimitedWriter {
+    fn new(limit: usize) -> Self {
+        Self {
+            buf: Vec::new(),
+            limit,
+            exceeded: false,
+        }
+    }
+}
+
+impl io::Write for LimitedWriter {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        if self.buf.len().saturating_add(bytes.len()) > self.limit {
+            self.exceeded = true;
+            return Err(io::Error::other("template output too large"));
+        }
+
+        self.buf.extend_from_slice(bytes);
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// src/infra/services/template_renderer.rs
+// The adapter stored on AppState: routes each source to the engine that owns it.
+pub struct EngineTemplateRenderer {
+    builtin: AskamaRenderer,
+    user: MinijinjaRenderer,
+}
+
+impl EngineTemplateRenderer {
+    pub fn new(user_limits: SandboxLimits) -> Self {
+        Self {
+            builtin: AskamaRenderer,
+            user: MinijinjaRenderer::new(user_limits),
+        }
+    }
+}
+
+impl TemplateRenderer for EngineTemplateRenderer {
+    fn render(&self, source: TemplateSource<'_>, context: &Value) -> Result<String, ServiceError> {
+        match source {
+            TemplateSource::Builtin(_) => self.builtin.render(source, context),
+            TemplateSource::User(_) => self.user.render(source, context),
+        }
+    }
+}
+
+// src/handlers/app/state.rs (built once at startup)
+//
+// let templates: Arc<dyn TemplateRenderer> =
+//     Arc::new(EngineTemplateRenderer::new(SandboxLimits::default()));
+//
+// Handlers pass `Arc::clone(&state.templates)` into usecases that render.
+
+// Usage in a usecase:
+//
+// let body = self.templates.render(
+//     TemplateSource::Builtin("example_notification"),
+//     &serde_json::json!({
+//         "recipient_name": recipient.display_name(),
+//         "entity_name": entity.column_text().as_str(),
+//         "status": entity.status().as_str(),
+//     }),
+// )?;
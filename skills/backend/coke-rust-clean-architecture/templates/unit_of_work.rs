@@ -0,0 +1,285 @@
+// Template: unit of work spanning several repositories. A usecase begins a
+// scope, gets repositories bound to the scope's transaction, and commits or
+// rolls back. Only domain ports cross the boundary; the connection, the
+// transaction manager, and Diesel stay in infra.
+//
+// Use this when one usecase writes through more than one repository. A single
+// repository method that needs atomicity keeps its own `conn.transaction(...)`;
+// inside a scope, Diesel turns that into a savepoint.
+
+// src/domain/repositories/unit_of_work.rs
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::events::DomainEvent;
+use crate::domain::repositories::{ExampleRepository, RepoError};
+
+#[async_trait]
+pub trait UnitOfWork: Send + Sync {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWorkScope>, RepoError>;
+}
+
+// Call exactly one of `commit` or `rollback`. A scope dropped without either is
+// rolled back in the background.
+#[async_trait]
+pub trait UnitOfWorkScope: Send + Sync {
+    fn example_repo(&self) -> Arc<dyn ExampleRepository>;
+
+    fn outbox_writer(&self) -> Arc<dyn OutboxWriter>;
+
+    async fn commit(self: Box<Self>) -> Result<(), RepoError>;
+
+    async fn rollback(self: Box<Self>) -> Result<(), RepoError>;
+}
+
+// src/domain/repositories/outbox_writer.rs
+// Write side of outbox.rs for usecases that record events through a scope.
+#[async_trait]
+pub trait OutboxWriter: Send + Sync {
+    async fn append(&self, events: &[DomainEvent]) -> Result<(), RepoError>;
+}
+
+// src/infra/db/connection_source.rs
+// Lets one repository implementation run against the pool or a scope's
+// transaction. Match `PgPooledConnection` to the pool in postgres_connection.rs.
+use std::ops::{Deref, DerefMut};
+
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::AsyncPgConnection;
+use tokio::sync::{Mutex, OwnedMutexGuard};
+
+use crate::infra::db::postgres_connection::PgPool;
+use crate::infra::db::repositories::error_mapping::map_pool_error;
+
+pub(crate) type PgPooledConnection = Object<AsyncPgConnection>;
+
+pub(crate) enum ConnectionSource {
+    Pool(Arc<PgPool>),
+    Transaction(Arc<Mutex<PgPooledConnection>>),
+}
+
+pub(crate) enum ConnectionGuard {
+    Pooled(PgPooledConnection),
+    Transaction(OwnedMutexGuard<PgPooledConnection>),
+}
+
+impl ConnectionSource {
+    pub(crate) async fn get(&self) -> Result<ConnectionGuard, RepoError> {
+        match self {
+            Self::Pool(pool) => pool
+                .get()
+                .await
+                .map(ConnectionGuard::Pooled)
+                .map_err(map_pool_error),
+            Self::Transaction(conn) => Ok(ConnectionGuard::Transaction(
+                Arc::clone(conn).lock_owned().await,
+            )),
+        }
+    }
+}
+
+impl Deref for ConnectionGuard {
+    type Target = AsyncPgConnection;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Pooled(conn) => conn,
+            Self::Transaction(conn) => conn,
+        }
+    }
+}
+
+impl DerefMut for ConnectionGuard {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Self::Pooled(conn) => conn,
+            Self::Transaction(conn) => conn,
+        }
+    }
+}
+
+// src/infra/db/repositories/example_postgres.rs
+// Swap `pool: Arc<PgPool>` for `source: ConnectionSource`, then in every method
+// replace `self.pool.get().await.map_err(map_pool_error)?` with
+// `self.source.get().await?` and pass `&mut *conn` to queries.
+//
+// impl ExamplePostgres {
+//     pub fn new(pool: Arc<PgPool>) -> Self {
+//         Self {
+//             source: ConnectionSource::Pool(pool),
+//         }
+//     }
+//
+//     pub(crate) fn in_transaction(conn: Arc<Mutex<PgPooledConnection>>) -> Self {
+//         Self {
+//             source: ConnectionSource::Transaction(conn),
+//         }
+//     }
+// }
+
+// src/infra/db/repositories/outbox_writer_postgres.rs
+use crate::infra::db::repositories::error_mapping::map_diesel_error;
+use crate::infra::db::repositories::outbox_postgres::{insert_outbox_rows, NewOutboxEventRow};
+
+pub struct OutboxWriterPostgres {
+    source: ConnectionSource,
+}
+
+impl OutboxWriterPostgres {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self {
+            source: ConnectionSource::Pool(pool),
+        }
+    }
+
+    pub(crate) fn in_transaction(conn: Arc<Mutex<PgPooledConnection>>) -> Self {
+        Self {
+            source: ConnectionSource::Transaction(conn),
+        }
+    }
+}
+
+#[async_trait]
+impl OutboxWriter for OutboxWriterPostgres {
+    async fn append(&self, events: &[DomainEvent]) -> Result<(), RepoError> {
+        let rows = events
+            .iter()
+            .map(NewOutboxEventRow::from_domain)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut conn = self.source.get().await?;
+
+        insert_outbox_rows(&mut conn, &rows)
+            .await
+            .map_err(|err| map_diesel_error("outbox.append", err))?;
+
+        Ok(())
+    }
+}
+
+// src/infra/db/unit_of_work.rs
+use diesel_async::{AnsiTransactionManager, TransactionManager};
+use tracing::error;
+
+use crate::infra::db::repositories::example_postgres::ExamplePostgres;
+
+pub struct PgUnitOfWork {
+    pool: Arc<PgPool>,
+}
+
+impl PgUnitOfWork {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for PgUnitOfWork {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWorkScope>, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        AnsiTransactionManager::begin_transaction(&mut *conn)
+            .await
+            .map_err(|err| map_diesel_error("unit_of_work.begin", err))?;
+
+        Ok(Box::new(PgUnitOfWorkScope {
+            conn: Arc::new(Mutex::new(conn)),
+            finished: false,
+        }))
+    }
+}
+
+struct PgUnitOfWorkScope {
+    conn: Arc<Mutex<PgPooledConnection>>,
+    finished: bool,
+}
+
+#[async_trait]
+impl UnitOfWorkScope for PgUnitOfWorkScope {
+    fn example_repo(&self) -> Arc<dyn ExampleRepository> {
+        Arc::new(ExamplePostgres::in_transaction(Arc::clone(&self.conn)))
+    }
+
+    fn outbox_writer(&self) -> Arc<dyn OutboxWriter> {
+        Arc::new(OutboxWriterPostgres::in_transaction(Arc::clone(&self.conn)))
+    }
+
+    async fn commit(mut self: Box<Self>) -> Result<(), RepoError> {
+        self.finished = true;
+        let mut conn = self.conn.lock().await;
+
+        AnsiTransactionManager::commit_transaction(&mut **conn)
+            .await
+            .map_err(|err| map_diesel_error("unit_of_work.commit", err))
+    }
+
+    async fn rollback(mut self: Box<Self>) -> Result<(), RepoError> {
+        self.finished = true;
+        let mut conn = self.conn.lock().await;
+
+        AnsiTransactionManager::rollback_transaction(&mut **conn)
+            .await
+            .map_err(|err| map_diesel_error("unit_of_work.rollback", err))
+    }
+}
+
+// Returning a connection to the pool mid-transaction would leak the open
+// transaction into the next checkout, so an abandoned scope rolls back first.
+impl Drop for PgUnitOfWorkScope {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+
+        let conn = Arc::clone(&self.conn);
+        tokio::spawn(async move {
+            let mut conn = conn.lock().await;
+            if let Err(err) = AnsiTransactionManager::rollback_transaction(&mut **conn).await {
+                error!(error = %err, "abandoned unit of work rollback failed");
+            }
+        });
+    }
+}
+
+// src/usecases/example_feature/create_with_events.rs
+use crate::domain::entities::ExampleEntity;
+use crate::domain::services::Clock;
+use crate::domain::value_objects::ExampleEntityName;
+use crate::usecases::UsecaseError;
+
+pub struct CreateExampleEntityWithEventsUseCase {
+    uow: Arc<dyn UnitOfWork>,
+    clock: Arc<dyn Clock>,
+}
+
+impl CreateExampleEntityWithEventsUseCase {
+    pub fn new(uow: Arc<dyn UnitOfWork>, clock: Arc<dyn Clock>) -> Self {
+        Self { uow, clock }
+    }
+
+    pub async fn execute(
+        &self,
+        owner_id: uuid::Uuid,
+        column_text: String,
+        column_url: String,
+    ) -> Result<ExampleEntity, UsecaseError> {
+        let column_text = ExampleEntityName::new(column_text)?;
+        let mut entity = ExampleEntity::new(owner_id, column_text, column_url, self.clock.now());
+        let events = entity.take_events();
+
+        let scope = self.uow.begin().await?;
+        let written = async {
+            scope.example_repo().create(&entity).await?;
+            scope.outbox_writer().append(&events).await
+        }
+        .await;
+
+        if let Err(err) = written {
+            scope.rollback().await?;
+            return Err(err.into());
+        }
+
+        scope.commit().await?;
+        Ok(entity)
+    }
+}
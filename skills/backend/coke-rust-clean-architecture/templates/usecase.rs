@@ -8,6 +8,7 @@ use uuid::Uuid;
 
 use crate::domain::entities::ExampleEntity;
 use crate::domain::repositories::ExampleRepository;
+use crate::domain::services::Clock;
 use crate::domain::value_objects::ExampleEntityName;
 use crate::usecases::UsecaseError;
 
@@ -28,11 +29,15 @@ pub struct CreateExampleEntityOutput {
 
 pub struct CreateExampleEntityUseCase {
     example_repo: Arc<dyn ExampleRepository>,
+    clock: Arc<dyn Clock>,
 }
 
 impl CreateExampleEntityUseCase {
-    pub fn new(example_repo: Arc<dyn ExampleRepository>) -> Self {
-        Self { example_repo }
+    pub fn new(example_repo: Arc<dyn ExampleRepository>, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            example_repo,
+            clock,
+        }
     }
 
     pub async fn execute(
@@ -41,7 +46,12 @@ impl CreateExampleEntityUseCase {
     ) -> Result<CreateExampleEntityOutput, UsecaseError> {
         let column_text = ExampleEntityName::new(input.column_text)?;
 
-        let entity = ExampleEntity::new(input.owner_id, column_text, input.column_url);
+        let entity = ExampleEntity::new(
+            input.owner_id,
+            column_text,
+            input.column_url,
+            self.clock.now(),
+        );
 
         self.example_repo.create(&entity).await?;
 
@@ -0,0 +1,14 @@
+# Template: copy to locales/en-US/validation.ftl and translate into one file per
+# locale (validation_messages.ja.ftl is the Japanese one). Every key emitted through
+# DomainError::invalid_field must exist in the default locale; `$field` is always
+# set, other arguments only where the value object adds them with `with_param`.
+
+validation-required = { $field } is required.
+validation-too-short = { $field } must be at least { $min } characters.
+validation-too-long = { $field } must be at most { $max } characters.
+validation-too-many = { $field } must contain at most { $max } entries.
+validation-invalid-format = { $field } has an invalid format.
+validation-out-of-range = { $field } is out of the allowed range.
+validation-not-allowed = { $field } contains a value that is not allowed.
+validation-unknown-value = { $field } has an unknown value.
+validation-conflicting-values = { $field } contains conflicting values.
@@ -0,0 +1,13 @@
+# Template: copy to locales/ja/validation.ftl next to the en-US file from
+# validation_messages.ftl. Keep the same keys; a key missing here falls back to
+# the default locale.
+
+validation-required = { $field } は必須です。
+validation-too-short = { $field } は { $min } 文字以上で入力してください。
+validation-too-long = { $field } は { $max } 文字以内で入力してください。
+validation-too-many = { $field } は { $max } 件以内にしてください。
+validation-invalid-format = { $field } の形式が正しくありません。
+validation-out-of-range = { $field } が許可された範囲外です。
+validation-not-allowed = { $field } に許可されていない値が含まれています。
+validation-unknown-value = { $field } の値が不明です。
+validation-conflicting-values = { $field } に矛盾する値が含まれています。
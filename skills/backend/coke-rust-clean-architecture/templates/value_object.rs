@@ -65,17 +65,12 @@ impl ExampleEntityName {
         let trimmed = value.trim();
 
         if trimmed.is_empty() {
-            return Err(DomainError::InvalidField {
-                field: "column_text",
-                reason: "must not be empty",
-            });
+            return Err(DomainError::invalid_field("column_text", "validation-required"));
         }
 
         if trimmed.len() > Self::MAX_LENGTH {
-            return Err(DomainError::InvalidField {
-                field: "column_text",
-                reason: "exceeds maximum length",
-            });
+            return Err(DomainError::invalid_field("column_text", "validation-too-long")
+                .with_param("max", Self::MAX_LENGTH));
         }
 
         Ok(Self(trimmed.to_string()))
@@ -143,10 +138,7 @@ impl FromStr for ExampleEntityStatus {
         match value {
             "active" => Ok(Self::Active),
             "inactive" => Ok(Self::Inactive),
-            _ => Err(DomainError::InvalidField {
-                field: "status",
-                reason: "unknown status value",
-            }),
+            _ => Err(DomainError::invalid_field("status", "validation-unknown-value")),
         }
     }
 }
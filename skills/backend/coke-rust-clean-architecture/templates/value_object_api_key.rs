@@ -0,0 +1,91 @@
+// Template: API key value objects. `ApiKey` is the plaintext shown to the user
+// exactly once; only `ApiKeyHash` is persisted. Keys carry 256 bits of entropy,
+// so a fast SHA-256 digest is sufficient and a slow password hash is not needed.
+
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+use crate::domain::DomainError;
+
+// src/domain/value_objects/validated/api_key.rs
+pub struct ApiKey(String);
+
+impl ApiKey {
+    const PREFIX: &'static str = "sk_live_";
+    const SECRET_BYTES: usize = 32;
+    const DISPLAY_PREFIX_LENGTH: usize = 12;
+
+    pub fn generate() -> Self {
+        let mut secret = [0_u8; Self::SECRET_BYTES];
+        OsRng.fill_bytes(&mut secret);
+
+        Self(format!("{}{}", Self::PREFIX, hex::encode(secret)))
+    }
+
+    pub fn parse(value: String) -> Result<Self, DomainError> {
+        let trimmed = value.trim();
+        let expected_length = Self::PREFIX.len() + Self::SECRET_BYTES * 2;
+
+        let is_hex = trimmed
+            .strip_prefix(Self::PREFIX)
+            .is_some_and(|secret| secret.chars().all(|c| c.is_ascii_hexdigit()));
+
+        if trimmed.len() != expected_length || !is_hex {
+            return Err(DomainError::invalid_field("api_key", "validation-invalid-format"));
+        }
+
+        Ok(Self(trimmed.to_string()))
+    }
+
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    // Safe to store and show in listings so users can tell keys apart.
+    pub fn display_prefix(&self) -> &str {
+        &self.0[..Self::DISPLAY_PREFIX_LENGTH]
+    }
+
+    pub fn hash(&self) -> ApiKeyHash {
+        ApiKeyHash(Sha256::digest(self.0.as_bytes()).into())
+    }
+}
+
+// Never print the plaintext key, including through `{:?}` in logs.
+impl std::fmt::Debug for ApiKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ApiKey({}…)", self.display_prefix())
+    }
+}
+
+// src/domain/value_objects/validated/api_key_hash.rs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ApiKeyHash([u8; 32]);
+
+impl ApiKeyHash {
+    pub fn from_trusted(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn from_hex(value: &str) -> Result<Self, DomainError> {
+        let mut bytes = [0_u8; 32];
+        hex::decode_to_slice(value, &mut bytes)
+            .map_err(|_| DomainError::invalid_field("api_key_hash", "validation-invalid-format"))?;
+
+        Ok(Self(bytes))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0)
+    }
+
+    pub fn verify(&self, candidate: &ApiKey) -> bool {
+        self.0.ct_eq(candidate.hash().as_bytes()).into()
+    }
+}
@@ -0,0 +1,206 @@
+// Template: validated email address value object. Follows the ExampleEntityName
+// pattern. Validates the RFC 5321/5322 dot-atom subset accepted by common mail
+// providers; quoted local parts and IP-literal domains are rejected on purpose.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::DomainError;
+
+// src/domain/value_objects/validated/email_address.rs
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EmailAddress(String);
+
+impl EmailAddress {
+    const MAX_LENGTH: usize = 254;
+    const MAX_LOCAL_LENGTH: usize = 64;
+    const MAX_LABEL_LENGTH: usize = 63;
+
+    pub fn new(value: String) -> Result<Self, DomainError> {
+        let normalized = value.trim().to_lowercase();
+
+        if normalized.is_empty() {
+            return Err(Self::invalid("validation-required"));
+        }
+
+        if normalized.len() > Self::MAX_LENGTH {
+            return Err(Self::invalid("validation-too-long").with_param("max", Self::MAX_LENGTH));
+        }
+
+        let Some((local, domain)) = normalized.rsplit_once('@') else {
+            return Err(Self::invalid("validation-invalid-format"));
+        };
+
+        Self::validate_local(local)?;
+        Self::validate_domain(domain)?;
+
+        Ok(Self(normalized))
+    }
+
+    pub fn from_trusted(value: String) -> Self {
+        Self(value)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    pub fn domain(&self) -> &str {
+        self.0.rsplit_once('@').map_or("", |(_, domain)| domain)
+    }
+
+    fn validate_local(local: &str) -> Result<(), DomainError> {
+        if local.is_empty() || local.len() > Self::MAX_LOCAL_LENGTH {
+            return Err(Self::invalid("validation-invalid-format"));
+        }
+
+        if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+            return Err(Self::invalid("validation-invalid-format"));
+        }
+
+        let is_atext = |c: char| c.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~-.".contains(c);
+
+        if !local.chars().all(is_atext) {
+            return Err(Self::invalid("validation-invalid-format"));
+        }
+
+        Ok(())
+    }
+
+    fn validate_domain(domain: &str) -> Result<(), DomainError> {
+        let labels: Vec<&str> = domain.split('.').collect();
+
+        if labels.len() < 2 {
+            return Err(Self::invalid("validation-invalid-format"));
+        }
+
+        for label in labels {
+            if label.is_empty() || label.len() > Self::MAX_LABEL_LENGTH {
+                return Err(Self::invalid("validation-invalid-format"));
+            }
+
+            if label.starts_with('-') || label.ends_with('-') {
+                return Err(Self::invalid("validation-invalid-format"));
+            }
+
+            if !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+                return Err(Self::invalid("validation-invalid-format"));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn invalid(key: &'static str) -> DomainError {
+        DomainError::invalid_field("email", key)
+    }
+}
+
+impl AsRef<str> for EmailAddress {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for EmailAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// src/domain/value_objects/validated/mod.rs (addition)
+// #[cfg(test)]
+// mod email_address_test;
+
+// src/domain/value_objects/validated/email_address_test.rs
+use super::email_address::EmailAddress;
+use crate::domain::DomainError;
+
+fn error_key(value: &str) -> &'static str {
+    match EmailAddress::new(value.to_string()) {
+        Err(DomainError::InvalidField { key, .. }) => key,
+        other => panic!("expected InvalidField for {value:?}, got {other:?}"),
+    }
+}
+
+#[test]
+fn accepts_and_lowercases_a_valid_address() {
+    let email = EmailAddress::new("  Jane.Doe+News@Example.COM ".to_string()).unwrap();
+
+    assert_eq!(email.as_str(), "jane.doe+news@example.com");
+    assert_eq!(email.domain(), "example.com");
+}
+
+#[test]
+fn rejects_empty_input() {
+    assert_eq!(error_key("   "), "validation-required");
+}
+
+#[test]
+fn rejects_missing_at_sign() {
+    assert_eq!(error_key("jane.example.com"), "validation-invalid-format");
+}
+
+#[test]
+fn rejects_empty_local_part() {
+    assert_eq!(error_key("@example.com"), "validation-invalid-format");
+}
+
+#[test]
+fn rejects_empty_domain() {
+    assert_eq!(error_key("jane@"), "validation-invalid-format");
+}
+
+#[test]
+fn rejects_single_label_domain() {
+    assert_eq!(error_key("jane@localhost"), "validation-invalid-format");
+}
+
+#[test]
+fn rejects_consecutive_dots() {
+    assert_eq!(
+        error_key("jane..doe@example.com"),
+        "validation-invalid-format"
+    );
+    assert_eq!(error_key("jane@example..com"), "validation-invalid-format");
+}
+
+#[test]
+fn rejects_leading_and_trailing_dots_in_local_part() {
+    assert_eq!(error_key(".jane@example.com"), "validation-invalid-format");
+    assert_eq!(error_key("jane.@example.com"), "validation-invalid-format");
+}
+
+#[test]
+fn rejects_address_over_max_length() {
+    let local = "a".repeat(64);
+    let domain = format!(
+        "{}.com",
+        ["b".repeat(63), "c".repeat(63), "d".repeat(60)].join(".")
+    );
+    let value = format!("{local}@{domain}");
+    assert!(value.len() > 254);
+
+    match EmailAddress::new(value) {
+        Err(DomainError::InvalidField { key, params, .. }) => {
+            assert_eq!(key, "validation-too-long");
+            assert_eq!(params, vec![("max", "254".to_string())]);
+        }
+        other => panic!("expected validation-too-long, got {other:?}"),
+    }
+}
+
+#[test]
+fn rejects_local_part_over_64_characters() {
+    let value = format!("{}@example.com", "a".repeat(65));
+
+    assert_eq!(error_key(&value), "validation-invalid-format");
+}
+
+#[test]
+fn rejects_domain_label_with_leading_hyphen() {
+    assert_eq!(error_key("jane@-example.com"), "validation-invalid-format");
+}
@@ -0,0 +1,87 @@
+// Template: `define_id!` generates the same ID newtype as the ExampleEntityId
+// section of value_object.rs. Use it once a project has several aggregates so
+// every ID keeps identical behavior. Keep the expanded form when a single ID
+// needs custom behavior. Pass `generator = ...` to choose how `new()` creates
+// IDs for one aggregate; the default is random UUIDv4.
+
+// src/domain/value_objects/ids/define_id.rs
+#[macro_export]
+macro_rules! define_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $crate::define_id!(
+            $(#[$meta])*
+            $name,
+            generator = $crate::domain::value_objects::ids::id_generator::UuidV4Generator
+        );
+    };
+    ($(#[$meta:meta])* $name:ident, generator = $generator:ty) => {
+        $(#[$meta])*
+        #[derive(
+            Debug,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            Hash,
+            ::serde::Serialize,
+            ::serde::Deserialize,
+        )]
+        #[serde(transparent)]
+        pub struct $name(::uuid::Uuid);
+
+        impl $name {
+            pub fn new() -> Self {
+                Self(<$generator as $crate::domain::value_objects::ids::id_generator::IdGenerator>::generate())
+            }
+
+            pub fn from_uuid(uuid: ::uuid::Uuid) -> Self {
+                Self(uuid)
+            }
+
+            pub fn as_uuid(&self) -> &::uuid::Uuid {
+                &self.0
+            }
+
+            pub fn into_uuid(self) -> ::uuid::Uuid {
+                self.0
+            }
+        }
+
+        impl ::std::default::Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl ::std::convert::From<::uuid::Uuid> for $name {
+            fn from(uuid: ::uuid::Uuid) -> Self {
+                Self(uuid)
+            }
+        }
+
+        impl ::std::convert::From<$name> for ::uuid::Uuid {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+}
+
+// src/domain/value_objects/ids/example_entity_id.rs
+crate::define_id!(
+    /// Identifier of an `ExampleEntity`.
+    ExampleEntityId
+);
+
+// src/domain/value_objects/ids/example_event_id.rs
+// High-insert tables use time-ordered IDs so B-tree inserts stay append-mostly.
+crate::define_id!(
+    ExampleEventId,
+    generator = crate::domain::value_objects::ids::id_generator::UuidV7Generator
+);
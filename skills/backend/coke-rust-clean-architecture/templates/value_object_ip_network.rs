@@ -0,0 +1,172 @@
+// Template: CIDR network and allowlist value objects. Networks must be written in
+// canonical form (no host bits set), so `10.0.0.1/8` is rejected instead of being
+// silently widened.
+
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::DomainError;
+
+// src/domain/value_objects/validated/ip_network.rs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct IpNetwork {
+    network: IpAddr,
+    prefix: u8,
+}
+
+impl IpNetwork {
+    pub fn new(value: &str) -> Result<Self, DomainError> {
+        let trimmed = value.trim();
+
+        let (address, prefix) = match trimmed.split_once('/') {
+            Some((address, prefix)) => (address, Some(prefix)),
+            None => (trimmed, None),
+        };
+
+        let network =
+            IpAddr::from_str(address).map_err(|_| Self::invalid("validation-invalid-format"))?;
+        let max_prefix = Self::max_prefix(network);
+
+        let prefix = match prefix {
+            Some(prefix) => prefix
+                .parse::<u8>()
+                .ok()
+                .filter(|prefix| *prefix <= max_prefix)
+                .ok_or_else(|| Self::invalid("validation-out-of-range"))?,
+            None => max_prefix,
+        };
+
+        if Self::mask(network, prefix) != network {
+            return Err(Self::invalid("validation-invalid-format"));
+        }
+
+        Ok(Self { network, prefix })
+    }
+
+    pub fn network(&self) -> IpAddr {
+        self.network
+    }
+
+    pub fn prefix(&self) -> u8 {
+        self.prefix
+    }
+
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        let ip = match ip {
+            IpAddr::V6(v6) => v6.to_ipv4_mapped().map_or(ip, IpAddr::V4),
+            IpAddr::V4(_) => ip,
+        };
+
+        ip.is_ipv4() == self.network.is_ipv4() && Self::mask(ip, self.prefix) == self.network
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.contains(other.network) || other.contains(self.network)
+    }
+
+    fn max_prefix(ip: IpAddr) -> u8 {
+        match ip {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        }
+    }
+
+    fn mask(ip: IpAddr, prefix: u8) -> IpAddr {
+        match ip {
+            IpAddr::V4(v4) => {
+                let bits = u32::from(v4);
+                let mask = u32::MAX.checked_shl(32 - u32::from(prefix)).unwrap_or(0);
+                IpAddr::V4((bits & mask).into())
+            }
+            IpAddr::V6(v6) => {
+                let bits = u128::from(v6);
+                let mask = u128::MAX.checked_shl(128 - u32::from(prefix)).unwrap_or(0);
+                IpAddr::V6((bits & mask).into())
+            }
+        }
+    }
+
+    fn invalid(key: &'static str) -> DomainError {
+        DomainError::invalid_field("ip_network", key)
+    }
+}
+
+impl TryFrom<String> for IpNetwork {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(&value)
+    }
+}
+
+impl From<IpNetwork> for String {
+    fn from(network: IpNetwork) -> Self {
+        network.to_string()
+    }
+}
+
+impl std::fmt::Display for IpNetwork {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.network, self.prefix)
+    }
+}
+
+// src/domain/value_objects/validated/ip_allowlist.rs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<IpNetwork>", into = "Vec<IpNetwork>")]
+pub struct IpAllowlist(Vec<IpNetwork>);
+
+impl IpAllowlist {
+    const MAX_ENTRIES: usize = 100;
+
+    pub fn new(networks: Vec<IpNetwork>) -> Result<Self, DomainError> {
+        if networks.is_empty() {
+            return Err(Self::invalid("validation-required"));
+        }
+
+        if networks.len() > Self::MAX_ENTRIES {
+            return Err(Self::invalid("validation-too-many").with_param("max", Self::MAX_ENTRIES));
+        }
+
+        for (index, network) in networks.iter().enumerate() {
+            if networks[index + 1..].iter().any(|other| network.overlaps(other)) {
+                return Err(Self::invalid("validation-conflicting-values"));
+            }
+        }
+
+        Ok(Self(networks))
+    }
+
+    pub fn from_trusted(networks: Vec<IpNetwork>) -> Self {
+        Self(networks)
+    }
+
+    pub fn allows(&self, ip: IpAddr) -> bool {
+        self.0.iter().any(|network| network.contains(ip))
+    }
+
+    pub fn networks(&self) -> &[IpNetwork] {
+        &self.0
+    }
+
+    fn invalid(key: &'static str) -> DomainError {
+        DomainError::invalid_field("ip_allowlist", key)
+    }
+}
+
+impl TryFrom<Vec<IpNetwork>> for IpAllowlist {
+    type Error = DomainError;
+
+    fn try_from(networks: Vec<IpNetwork>) -> Result<Self, Self::Error> {
+        Self::new(networks)
+    }
+}
+
+impl From<IpAllowlist> for Vec<IpNetwork> {
+    fn from(allowlist: IpAllowlist) -> Self {
+        allowlist.0
+    }
+}
@@ -0,0 +1,136 @@
+// Template: money value object stored as integer minor units plus an ISO 4217
+// currency code. Amounts never use floats. Arithmetic and comparison are only
+// defined within one currency and return DomainError instead of panicking.
+
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::DomainError;
+
+// src/domain/value_objects/validated/currency_code.rs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CurrencyCode([u8; 3]);
+
+impl CurrencyCode {
+    pub fn new(value: &str) -> Result<Self, DomainError> {
+        let bytes = value.trim().as_bytes();
+
+        match bytes {
+            [a, b, c] if bytes.iter().all(u8::is_ascii_alphabetic) => Ok(Self([
+                a.to_ascii_uppercase(),
+                b.to_ascii_uppercase(),
+                c.to_ascii_uppercase(),
+            ])),
+            _ => Err(DomainError::invalid_field("currency", "validation-invalid-format")),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        // Constructed only from ASCII letters.
+        std::str::from_utf8(&self.0).unwrap_or("XXX")
+    }
+}
+
+impl TryFrom<String> for CurrencyCode {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(&value)
+    }
+}
+
+impl From<CurrencyCode> for String {
+    fn from(code: CurrencyCode) -> Self {
+        code.as_str().to_string()
+    }
+}
+
+impl std::fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+// src/domain/value_objects/validated/money.rs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Money {
+    minor_units: i64,
+    currency: CurrencyCode,
+}
+
+impl Money {
+    pub fn new(minor_units: i64, currency: CurrencyCode) -> Self {
+        Self {
+            minor_units,
+            currency,
+        }
+    }
+
+    pub fn zero(currency: CurrencyCode) -> Self {
+        Self::new(0, currency)
+    }
+
+    pub fn minor_units(&self) -> i64 {
+        self.minor_units
+    }
+
+    pub fn currency(&self) -> CurrencyCode {
+        self.currency
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.minor_units < 0
+    }
+
+    pub fn checked_add(self, other: Self) -> Result<Self, DomainError> {
+        self.ensure_same_currency(other)?;
+        self.minor_units
+            .checked_add(other.minor_units)
+            .map(|minor_units| Self::new(minor_units, self.currency))
+            .ok_or_else(Self::overflow)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Result<Self, DomainError> {
+        self.ensure_same_currency(other)?;
+        self.minor_units
+            .checked_sub(other.minor_units)
+            .map(|minor_units| Self::new(minor_units, self.currency))
+            .ok_or_else(Self::overflow)
+    }
+
+    pub fn checked_mul(self, factor: i64) -> Result<Self, DomainError> {
+        self.minor_units
+            .checked_mul(factor)
+            .map(|minor_units| Self::new(minor_units, self.currency))
+            .ok_or_else(Self::overflow)
+    }
+
+    // Deliberately not `PartialOrd`: amounts in different currencies have no order.
+    pub fn checked_cmp(&self, other: &Self) -> Result<Ordering, DomainError> {
+        self.ensure_same_currency(*other)?;
+        Ok(self.minor_units.cmp(&other.minor_units))
+    }
+
+    fn ensure_same_currency(&self, other: Self) -> Result<(), DomainError> {
+        if self.currency != other.currency {
+            return Err(DomainError::InvariantViolation(format!(
+                "currency mismatch: {} vs {}",
+                self.currency, other.currency
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn overflow() -> DomainError {
+        DomainError::InvariantViolation("money amount overflow".to_string())
+    }
+}
+
+impl std::fmt::Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.minor_units, self.currency)
+    }
+}
@@ -0,0 +1,239 @@
+// Template: SSRF-safe outbound URL for anything that sends requests to
+// user-supplied destinations. `new()` performs the static checks in the domain;
+// DNS resolution is IO, so `resolve_and_check()` lives in infra and reuses the
+// same address rule.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use url::{Host, Url};
+
+use crate::domain::DomainError;
+
+// src/domain/value_objects/validated/outbound_url.rs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboundUrl(Url);
+
+impl OutboundUrl {
+    const MAX_LENGTH: usize = 2048;
+    const ALLOWED_PORTS: [u16; 4] = [80, 443, 8080, 8443];
+
+    pub fn new(value: String) -> Result<Self, DomainError> {
+        let trimmed = value.trim();
+
+        if trimmed.len() > Self::MAX_LENGTH {
+            return Err(Self::invalid("validation-too-long").with_param("max", Self::MAX_LENGTH));
+        }
+
+        let url = Url::parse(trimmed).map_err(|_| Self::invalid("validation-invalid-format"))?;
+
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(Self::invalid("validation-not-allowed"));
+        }
+
+        if !url.username().is_empty() || url.password().is_some() {
+            return Err(Self::invalid("validation-not-allowed"));
+        }
+
+        let port = url
+            .port_or_known_default()
+            .ok_or_else(|| Self::invalid("validation-invalid-format"))?;
+
+        if !Self::ALLOWED_PORTS.contains(&port) {
+            return Err(Self::invalid("validation-not-allowed"));
+        }
+
+        match url.host() {
+            Some(Host::Domain(domain)) if is_localhost(domain) => {
+                return Err(Self::invalid("validation-not-allowed"));
+            }
+            Some(Host::Domain(_)) => {}
+            Some(Host::Ipv4(ip)) => Self::check_ip(IpAddr::V4(ip))?,
+            Some(Host::Ipv6(ip)) => Self::check_ip(IpAddr::V6(ip))?,
+            None => return Err(Self::invalid("validation-invalid-format")),
+        }
+
+        Ok(Self(url))
+    }
+
+    // Takes an already-parsed `Url`, like `IpAllowlist::from_trusted`. The row mapper
+    // parses the stored text and reports a failure as a repository error.
+    pub fn from_trusted(url: Url) -> Self {
+        Self(url)
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    pub fn as_url(&self) -> &Url {
+        &self.0
+    }
+
+    // Applied to IP literals in `new()` and to every resolved address in infra.
+    pub fn check_ip(ip: IpAddr) -> Result<(), DomainError> {
+        let is_public = match ip {
+            IpAddr::V4(ip) => is_public_v4(ip),
+            IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+                Some(mapped) => is_public_v4(mapped),
+                None => is_public_v6(ip),
+            },
+        };
+
+        if !is_public {
+            return Err(Self::invalid("validation-not-allowed"));
+        }
+
+        Ok(())
+    }
+
+    fn invalid(key: &'static str) -> DomainError {
+        DomainError::invalid_field("url", key)
+    }
+}
+
+// `localhost.` (trailing dot) and every `*.localhost` name resolve to loopback
+// on most systems (RFC 6761), so the name alone is enough to reject them.
+fn is_localhost(domain: &str) -> bool {
+    let domain = domain
+        .strip_suffix('.')
+        .unwrap_or(domain)
+        .to_ascii_lowercase();
+
+    domain == "localhost" || domain.ends_with(".localhost")
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    let is_this_network = a == 0;
+    let is_shared = a == 100 && (64..=127).contains(&b);
+    let is_benchmarking = a == 198 && (b & 0xfe) == 18;
+    // 240.0.0.0/4, which also covers the broadcast address.
+    let is_reserved = a >= 240;
+
+    !(ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        || is_this_network
+        || is_shared
+        || is_benchmarking
+        || is_reserved)
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let segments = ip.segments();
+    let first = segments[0];
+    let is_unique_local = (first & 0xfe00) == 0xfc00;
+    let is_link_local = (first & 0xffc0) == 0xfe80;
+    let is_site_local = (first & 0xffc0) == 0xfec0;
+    // NAT64 hands the request to whatever IPv4 address is embedded, private ones included.
+    let is_nat64 = segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0];
+    // 6to4 embeds the relay's IPv4 address in bits 16..48.
+    let [_, high, low, ..] = segments;
+    let embedded = Ipv4Addr::from((u32::from(high) << 16) | u32::from(low));
+    let is_private_6to4 = first == 0x2002 && !is_public_v4(embedded);
+
+    !(ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        || is_unique_local
+        || is_link_local
+        || is_site_local
+        || is_nat64
+        || is_private_6to4)
+}
+
+impl std::fmt::Display for OutboundUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// src/infra/services/outbound_url_resolver.rs
+// DNS rebinding protection: resolve once, check every address, and connect only to
+// the returned addresses instead of letting the HTTP client resolve the name again.
+use std::net::SocketAddr;
+
+use crate::domain::services::ServiceError;
+use crate::domain::value_objects::OutboundUrl;
+
+pub async fn resolve_and_check(url: &OutboundUrl) -> Result<Vec<SocketAddr>, ServiceError> {
+    let host = url
+        .as_url()
+        .host_str()
+        .ok_or_else(|| ServiceError::InvalidRequest("url has no host".to_string()))?;
+    let port = url
+        .as_url()
+        .port_or_known_default()
+        .ok_or_else(|| ServiceError::InvalidRequest("url has no port".to_string()))?;
+
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|err| ServiceError::Unavailable(err.to_string()))?
+        .collect();
+
+    if addrs.is_empty() {
+        return Err(ServiceError::Unavailable(format!("{host} did not resolve")));
+    }
+
+    for addr in &addrs {
+        OutboundUrl::check_ip(addr.ip())
+            .map_err(|err| ServiceError::InvalidRequest(err.to_string()))?;
+    }
+
+    Ok(addrs)
+}
+
+// src/domain/value_objects/validated/mod.rs (addition)
+// #[cfg(test)]
+// mod outbound_url_test;
+
+// src/domain/value_objects/validated/outbound_url_test.rs
+use super::outbound_url::OutboundUrl;
+
+fn is_rejected(value: &str) -> bool {
+    OutboundUrl::new(value.to_string()).is_err()
+}
+
+#[test]
+fn public_hosts_are_accepted() {
+    assert!(!is_rejected("https://example.com/hook"));
+    assert!(!is_rejected("https://93.184.216.34/hook"));
+    assert!(!is_rejected("https://[2606:4700::1111]/hook"));
+    assert!(!is_rejected("https://[2002:5db8:d822::1]/hook"));
+}
+
+#[test]
+fn localhost_names_are_rejected() {
+    assert!(is_rejected("https://localhost/hook"));
+    assert!(is_rejected("https://LOCALHOST./hook"));
+    assert!(is_rejected("https://api.localhost/hook"));
+    assert!(is_rejected("https://api.localhost./hook"));
+}
+
+#[test]
+fn reserved_v4_ranges_are_rejected() {
+    for ip in [
+        "0.1.2.3",
+        "198.18.0.1",
+        "198.19.255.254",
+        "240.0.0.1",
+        "255.255.255.255",
+    ] {
+        assert!(is_rejected(&format!("https://{ip}/hook")), "{ip}");
+    }
+}
+
+#[test]
+fn v6_ranges_that_reach_private_hosts_are_rejected() {
+    for ip in [
+        "64:ff9b::a00:1",
+        "64:ff9b::5db8:d822",
+        "2002:a00:1::1",
+        "2002:7f00:1::1",
+        "fec0::1",
+    ] {
+        assert!(is_rejected(&format!("https://[{ip}]/hook")), "{ip}");
+    }
+}
@@ -0,0 +1,209 @@
+// Template: opaque keyset pagination cursor over (created_at, id). The token is
+// `<payload>.<mac>`, both base64url: an HMAC-SHA256 over the payload with a
+// server-side key, so an edited or forged token fails `decode` instead of
+// seeking to an arbitrary position. Repositories still apply owner and
+// visibility filters; the signature keeps clients from probing other rows.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::domain::DomainError;
+
+type HmacSha256 = Hmac<Sha256>;
+
+// src/domain/value_objects/validated/page_cursor.rs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageCursor {
+    created_at: DateTime<Utc>,
+    id: Uuid,
+}
+
+impl PageCursor {
+    const VERSION: &'static str = "v1";
+
+    pub fn new(created_at: DateTime<Utc>, id: Uuid) -> Self {
+        Self { created_at, id }
+    }
+
+    pub fn encode(&self, key: &PageCursorKey) -> String {
+        let raw = format!(
+            "{}:{}:{}",
+            Self::VERSION,
+            self.created_at.timestamp_micros(),
+            self.id
+        );
+        let mac = key.mac(raw.as_bytes()).finalize().into_bytes();
+
+        format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(raw),
+            URL_SAFE_NO_PAD.encode(mac)
+        )
+    }
+
+    pub fn decode(token: &str, key: &PageCursorKey) -> Result<Self, DomainError> {
+        let (payload, mac) = token.trim().split_once('.').ok_or_else(Self::invalid)?;
+        let bytes = URL_SAFE_NO_PAD
+            .decode(payload)
+            .map_err(|_| Self::invalid())?;
+        let mac = URL_SAFE_NO_PAD.decode(mac).map_err(|_| Self::invalid())?;
+
+        // Verify before parsing so a forged payload never reaches the parser.
+        key.mac(&bytes)
+            .verify_slice(&mac)
+            .map_err(|_| Self::invalid())?;
+
+        let raw = String::from_utf8(bytes).map_err(|_| Self::invalid())?;
+
+        let mut parts = raw.splitn(3, ':');
+        let (Some(version), Some(micros), Some(id)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(Self::invalid());
+        };
+
+        if version != Self::VERSION {
+            return Err(Self::invalid());
+        }
+
+        let micros: i64 = micros.parse().map_err(|_| Self::invalid())?;
+        let created_at =
+            DateTime::<Utc>::from_timestamp_micros(micros).ok_or_else(Self::invalid)?;
+        let id = Uuid::parse_str(id).map_err(|_| Self::invalid())?;
+
+        Ok(Self::new(created_at, id))
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    fn invalid() -> DomainError {
+        DomainError::invalid_field("cursor", "validation-invalid-format")
+    }
+}
+
+// Server-side signing key for cursors. Rotating it invalidates every
+// outstanding cursor; clients restart from the first page.
+#[derive(Clone)]
+pub struct PageCursorKey(Vec<u8>);
+
+impl PageCursorKey {
+    const MIN_LENGTH: usize = 32;
+
+    pub fn new(secret: String) -> Result<Self, DomainError> {
+        if secret.len() < Self::MIN_LENGTH {
+            return Err(
+                DomainError::invalid_field("cursor_key", "validation-too-short")
+                    .with_param("min", Self::MIN_LENGTH),
+            );
+        }
+
+        Ok(Self(secret.into_bytes()))
+    }
+
+    #[expect(clippy::expect_used, reason = "HMAC accepts keys of any length")]
+    fn mac(&self, payload: &[u8]) -> HmacSha256 {
+        let mut mac =
+            <HmacSha256 as Mac>::new_from_slice(&self.0).expect("HMAC accepts keys of any length");
+        mac.update(payload);
+        mac
+    }
+}
+
+// Never print key material, including through `{:?}` in logs.
+impl std::fmt::Debug for PageCursorKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PageCursorKey([redacted])")
+    }
+}
+
+// src/domain/value_objects/validated/mod.rs (addition)
+// #[cfg(test)]
+// mod page_cursor_test;
+
+// src/domain/value_objects/validated/page_cursor_test.rs
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{TimeZone, Utc};
+use uuid::Uuid;
+
+use super::page_cursor::{PageCursor, PageCursorKey};
+use crate::domain::DomainError;
+
+fn key() -> PageCursorKey {
+    PageCursorKey::new("test-cursor-key-0123456789abcdef".to_string()).unwrap()
+}
+
+fn cursor() -> PageCursor {
+    let created_at = Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap();
+    PageCursor::new(created_at, Uuid::new_v4())
+}
+
+#[test]
+fn decode_round_trips_encoded_cursor() {
+    let cursor = cursor();
+
+    let decoded = PageCursor::decode(&cursor.encode(&key()), &key()).unwrap();
+
+    assert_eq!(decoded, cursor);
+}
+
+#[test]
+fn decode_rejects_edited_payload() {
+    let token = cursor().encode(&key());
+    let (_, mac) = token.split_once('.').unwrap();
+    let forged = format!("v1:0:{}", Uuid::new_v4());
+    let tampered = format!("{}.{mac}", URL_SAFE_NO_PAD.encode(forged));
+
+    assert!(PageCursor::decode(&tampered, &key()).is_err());
+}
+
+#[test]
+fn decode_rejects_edited_mac() {
+    let token = cursor().encode(&key());
+    let (payload, _) = token.split_once('.').unwrap();
+    let tampered = format!("{payload}.{}", URL_SAFE_NO_PAD.encode([0_u8; 32]));
+
+    assert!(PageCursor::decode(&tampered, &key()).is_err());
+}
+
+#[test]
+fn decode_rejects_token_signed_with_another_key() {
+    let other = PageCursorKey::new("another-cursor-key-0123456789abcd".to_string()).unwrap();
+    let token = cursor().encode(&other);
+
+    assert!(PageCursor::decode(&token, &key()).is_err());
+}
+
+#[test]
+fn decode_rejects_malformed_tokens() {
+    let token = cursor().encode(&key());
+    let (payload, _) = token.split_once('.').unwrap();
+
+    for malformed in ["", ".", "not base64!", payload, "djE6MDox.bWFj"] {
+        assert!(
+            PageCursor::decode(malformed, &key()).is_err(),
+            "{malformed:?}"
+        );
+    }
+}
+
+#[test]
+fn decode_reports_cursor_field() {
+    let err = PageCursor::decode("garbage", &key()).unwrap_err();
+
+    assert!(matches!(err, DomainError::InvalidField { ref field, .. } if field == "cursor"));
+}
+
+#[test]
+fn key_rejects_short_secret() {
+    assert!(PageCursorKey::new("short".to_string()).is_err());
+}
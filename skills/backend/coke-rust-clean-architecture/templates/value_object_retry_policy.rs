@@ -0,0 +1,217 @@
+// Template: retry policy shared by usecases and background workers. Attempts are
+// 1-based: attempt 1 is the first retry after the initial failure. Jitter is
+// injected as a fraction so the delay calculation stays deterministic in tests.
+
+use std::time::Duration;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::DomainError;
+
+// src/domain/value_objects/enums/backoff_strategy.rs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackoffStrategy {
+    Fixed,
+    Exponential,
+    ExponentialWithJitter,
+}
+
+// src/domain/value_objects/validated/retry_policy.rs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RetryPolicyFields", into = "RetryPolicyFields")]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    strategy: BackoffStrategy,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RetryPolicyFields {
+    max_attempts: u32,
+    strategy: BackoffStrategy,
+    base_delay_ms: u64,
+    max_delay_ms: u64,
+}
+
+impl RetryPolicy {
+    const MAX_ATTEMPTS_LIMIT: u32 = 100;
+
+    pub fn new(
+        max_attempts: u32,
+        strategy: BackoffStrategy,
+        base_delay: Duration,
+        max_delay: Duration,
+    ) -> Result<Self, DomainError> {
+        if max_attempts == 0 || max_attempts > Self::MAX_ATTEMPTS_LIMIT {
+            return Err(DomainError::invalid_field("max_attempts", "validation-out-of-range")
+                .with_param("min", 1)
+                .with_param("max", Self::MAX_ATTEMPTS_LIMIT));
+        }
+
+        if base_delay.is_zero() {
+            return Err(DomainError::invalid_field("base_delay", "validation-out-of-range"));
+        }
+
+        if max_delay < base_delay {
+            return Err(DomainError::invalid_field("max_delay", "validation-out-of-range"));
+        }
+
+        Ok(Self {
+            max_attempts,
+            strategy,
+            base_delay,
+            max_delay,
+        })
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    pub fn strategy(&self) -> BackoffStrategy {
+        self.strategy
+    }
+
+    pub fn should_retry(&self, attempt: u32) -> bool {
+        attempt <= self.max_attempts
+    }
+
+    pub fn next_delay(&self, attempt: u32) -> Duration {
+        self.next_delay_with_jitter(attempt, rand::thread_rng().gen_range(0.0..=1.0))
+    }
+
+    // `jitter` is clamped to 0.0..=1.0 and only used by `ExponentialWithJitter`
+    // ("full jitter": a uniform delay between zero and the exponential delay).
+    pub fn next_delay_with_jitter(&self, attempt: u32, jitter: f64) -> Duration {
+        let exponential = || {
+            let factor = 2_u32.saturating_pow(attempt.saturating_sub(1));
+            self.base_delay
+                .checked_mul(factor)
+                .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+        };
+
+        match self.strategy {
+            BackoffStrategy::Fixed => self.base_delay,
+            BackoffStrategy::Exponential => exponential(),
+            BackoffStrategy::ExponentialWithJitter => exponential().mul_f64(jitter.clamp(0.0, 1.0)),
+        }
+    }
+}
+
+impl TryFrom<RetryPolicyFields> for RetryPolicy {
+    type Error = DomainError;
+
+    fn try_from(fields: RetryPolicyFields) -> Result<Self, Self::Error> {
+        Self::new(
+            fields.max_attempts,
+            fields.strategy,
+            Duration::from_millis(fields.base_delay_ms),
+            Duration::from_millis(fields.max_delay_ms),
+        )
+    }
+}
+
+impl From<RetryPolicy> for RetryPolicyFields {
+    fn from(policy: RetryPolicy) -> Self {
+        Self {
+            max_attempts: policy.max_attempts,
+            strategy: policy.strategy,
+            base_delay_ms: u64::try_from(policy.base_delay.as_millis()).unwrap_or(u64::MAX),
+            max_delay_ms: u64::try_from(policy.max_delay.as_millis()).unwrap_or(u64::MAX),
+        }
+    }
+}
+
+// src/domain/value_objects/validated/mod.rs (addition)
+// #[cfg(test)]
+// mod retry_policy_test;
+
+// src/domain/value_objects/validated/retry_policy_test.rs
+use std::time::Duration;
+
+use super::retry_policy::RetryPolicy;
+use crate::domain::value_objects::BackoffStrategy;
+
+fn policy(strategy: BackoffStrategy) -> RetryPolicy {
+    RetryPolicy::new(5, strategy, Duration::from_millis(100), Duration::from_millis(1_000)).unwrap()
+}
+
+#[test]
+fn exponential_delay_doubles_per_attempt() {
+    let policy = policy(BackoffStrategy::Exponential);
+
+    let delays: Vec<_> = (1..=4).map(|attempt| policy.next_delay(attempt)).collect();
+
+    assert_eq!(delays, [100, 200, 400, 800].map(Duration::from_millis));
+}
+
+#[test]
+fn exponential_delay_is_capped_at_max_delay() {
+    let policy = policy(BackoffStrategy::Exponential);
+
+    assert_eq!(policy.next_delay(5), Duration::from_millis(1_000));
+    assert_eq!(policy.next_delay(u32::MAX), Duration::from_millis(1_000));
+}
+
+#[test]
+fn fixed_delay_does_not_grow() {
+    let policy = policy(BackoffStrategy::Fixed);
+
+    assert_eq!(policy.next_delay(1), Duration::from_millis(100));
+    assert_eq!(policy.next_delay(5), Duration::from_millis(100));
+}
+
+#[test]
+fn jitter_scales_delay_between_zero_and_exponential() {
+    let policy = policy(BackoffStrategy::ExponentialWithJitter);
+
+    assert_eq!(policy.next_delay_with_jitter(3, 0.0), Duration::ZERO);
+    assert_eq!(policy.next_delay_with_jitter(3, 0.5), Duration::from_millis(200));
+    assert_eq!(policy.next_delay_with_jitter(3, 1.0), Duration::from_millis(400));
+}
+
+#[test]
+fn jitter_outside_unit_range_is_clamped() {
+    let policy = policy(BackoffStrategy::ExponentialWithJitter);
+
+    assert_eq!(policy.next_delay_with_jitter(3, -1.0), Duration::ZERO);
+    assert_eq!(policy.next_delay_with_jitter(3, 2.0), Duration::from_millis(400));
+}
+
+#[test]
+fn random_jitter_stays_within_cap() {
+    let policy = policy(BackoffStrategy::ExponentialWithJitter);
+
+    for attempt in 1..=10 {
+        assert!(policy.next_delay(attempt) <= Duration::from_millis(1_000));
+    }
+}
+
+#[test]
+fn should_retry_stops_after_max_attempts() {
+    let policy = policy(BackoffStrategy::Fixed);
+
+    assert!(policy.should_retry(5));
+    assert!(!policy.should_retry(6));
+}
+
+#[test]
+fn new_rejects_out_of_range_values() {
+    let base = Duration::from_millis(100);
+    let max = Duration::from_millis(1_000);
+
+    assert!(RetryPolicy::new(0, BackoffStrategy::Fixed, base, max).is_err());
+    assert!(RetryPolicy::new(101, BackoffStrategy::Fixed, base, max).is_err());
+    assert!(RetryPolicy::new(3, BackoffStrategy::Fixed, Duration::ZERO, max).is_err());
+    assert!(RetryPolicy::new(3, BackoffStrategy::Fixed, max, base).is_err());
+}
+
+#[test]
+fn deserialize_applies_constructor_validation() {
+    let json = r#"{"max_attempts":0,"strategy":"fixed","base_delay_ms":100,"max_delay_ms":1000}"#;
+
+    assert!(serde_json::from_str::<RetryPolicy>(json).is_err());
+}
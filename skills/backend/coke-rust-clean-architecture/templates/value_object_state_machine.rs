@@ -0,0 +1,116 @@
+// Template: status enums declare their transition table once and get
+// `can_transition_to()`, `transition_to()`, and `is_terminal()` from the trait.
+// Entities call `transition_to()` inside their state transition methods instead
+// of hand-coding `match` tables per enum.
+
+use crate::domain::DomainError;
+
+// src/domain/value_objects/enums/state_machine.rs
+pub trait StateMachine: Copy + PartialEq + std::fmt::Debug + Sized + 'static {
+    const STATES: &'static [Self];
+    const TRANSITIONS: &'static [(Self, Self)];
+
+    fn can_transition_to(self, next: Self) -> bool {
+        Self::TRANSITIONS
+            .iter()
+            .any(|(from, to)| *from == self && *to == next)
+    }
+
+    fn transition_to(self, next: Self) -> Result<Self, DomainError> {
+        if !self.can_transition_to(next) {
+            return Err(DomainError::InvariantViolation(format!(
+                "cannot transition from {self:?} to {next:?}"
+            )));
+        }
+
+        Ok(next)
+    }
+
+    fn is_terminal(self) -> bool {
+        !Self::TRANSITIONS.iter().any(|(from, _)| *from == self)
+    }
+
+    fn next_states(self) -> impl Iterator<Item = Self> {
+        Self::TRANSITIONS
+            .iter()
+            .filter(move |(from, _)| *from == self)
+            .map(|(_, to)| *to)
+    }
+}
+
+// Test helper for downstream domain tests. The caller writes the allowed pairs
+// out by hand, so a wrong entry in `TRANSITIONS` fails the test instead of
+// being compared with itself. Every pair of `STATES` is checked.
+#[cfg(test)]
+pub fn assert_transitions<S: StateMachine>(allowed: &[(S, S)]) {
+    for from in S::STATES {
+        for to in S::STATES {
+            assert_eq!(
+                from.transition_to(*to).is_ok(),
+                allowed.contains(&(*from, *to)),
+                "transition {from:?} -> {to:?}"
+            );
+        }
+    }
+
+    for (from, to) in allowed {
+        assert!(
+            S::STATES.contains(from) && S::STATES.contains(to),
+            "transition {from:?} -> {to:?} uses a state missing from STATES"
+        );
+    }
+}
+
+// src/domain/value_objects/enums/example_entity_status.rs
+// Extends the ExampleEntityStatus from value_object.rs with a transition table.
+impl StateMachine for ExampleEntityStatus {
+    const STATES: &'static [Self] = &[Self::Active, Self::Inactive];
+    const TRANSITIONS: &'static [(Self, Self)] = &[
+        (Self::Active, Self::Inactive),
+        (Self::Inactive, Self::Active),
+    ];
+}
+
+// src/domain/entities/example_entity.rs
+//
+// pub fn change_status(
+//     &mut self,
+//     status: ExampleEntityStatus,
+//     now: DateTime<Utc>,
+// ) -> Result<(), DomainError> {
+//     self.ensure_not_deleted()?;
+//     self.status = self.status.transition_to(status)?;
+//     self.updated_at = now;
+//     Ok(())
+// }
+
+// src/domain/value_objects/enums/mod.rs (addition)
+// #[cfg(test)]
+// mod state_machine_test;
+
+// src/domain/value_objects/enums/state_machine_test.rs
+use super::example_entity_status::ExampleEntityStatus;
+use super::state_machine::{assert_transitions, StateMachine};
+use crate::domain::DomainError;
+
+#[test]
+fn example_entity_status_transitions() {
+    use ExampleEntityStatus::{Active, Inactive};
+
+    assert_transitions::<ExampleEntityStatus>(&[(Active, Inactive), (Inactive, Active)]);
+}
+
+#[test]
+fn transition_to_same_status_is_rejected() {
+    let err = ExampleEntityStatus::Active
+        .transition_to(ExampleEntityStatus::Active)
+        .unwrap_err();
+
+    assert!(matches!(err, DomainError::InvariantViolation(_)));
+}
+
+#[test]
+fn example_entity_status_has_no_terminal_state() {
+    assert!(!ExampleEntityStatus::Active.is_terminal());
+    assert!(!ExampleEntityStatus::Inactive.is_terminal());
+}
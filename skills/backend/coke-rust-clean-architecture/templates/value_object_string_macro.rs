@@ -0,0 +1,112 @@
+// Template: `validated_string!` generates the ExampleEntityName shape from
+// value_object.rs (trim, length limits, optional regex, `from_trusted()`,
+// `as_str()`, `AsRef<str>`, `Display`). Use it so validated strings across the
+// domain share one behavior. Lengths are measured in bytes, like the expanded
+// template.
+
+// src/domain/value_objects/validated/validated_string.rs
+#[macro_export]
+macro_rules! validated_string {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            field: $field:literal,
+            min_length: $min:expr,
+            max_length: $max:expr,
+            $(pattern: $pattern:literal,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, ::serde::Serialize, ::serde::Deserialize)]
+        pub struct $name(String);
+
+        impl $name {
+            pub const MIN_LENGTH: usize = $min;
+            pub const MAX_LENGTH: usize = $max;
+
+            pub fn new(value: String) -> Result<Self, $crate::domain::DomainError> {
+                let trimmed = value.trim();
+
+                if trimmed.is_empty() && Self::MIN_LENGTH > 0 {
+                    return Err(Self::invalid("validation-required"));
+                }
+
+                if trimmed.len() < Self::MIN_LENGTH {
+                    return Err(
+                        Self::invalid("validation-too-short").with_param("min", Self::MIN_LENGTH)
+                    );
+                }
+
+                if trimmed.len() > Self::MAX_LENGTH {
+                    return Err(
+                        Self::invalid("validation-too-long").with_param("max", Self::MAX_LENGTH)
+                    );
+                }
+
+                $(
+                    static PATTERN: ::std::sync::LazyLock<::regex::Regex> =
+                        ::std::sync::LazyLock::new(|| {
+                            ::regex::Regex::new($pattern)
+                                .unwrap_or_else(|err| panic!("invalid pattern for {}: {err}", $field))
+                        });
+
+                    if !PATTERN.is_match(trimmed) {
+                        return Err(Self::invalid("validation-invalid-format"));
+                    }
+                )?
+
+                Ok(Self(trimmed.to_string()))
+            }
+
+            pub fn from_trusted(value: String) -> Self {
+                Self(value)
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            pub fn into_string(self) -> String {
+                self.0
+            }
+
+            fn invalid(key: &'static str) -> $crate::domain::DomainError {
+                $crate::domain::DomainError::invalid_field($field, key)
+            }
+        }
+
+        impl ::std::convert::AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+}
+
+// src/domain/value_objects/validated/example_entity_name.rs
+crate::validated_string! {
+    /// Display name of an `ExampleEntity`.
+    pub struct ExampleEntityName {
+        field: "column_text",
+        min_length: 1,
+        max_length: 255,
+    }
+}
+
+// src/domain/value_objects/validated/example_entity_slug.rs
+// The pattern is a literal, so an invalid regex fails on first use in any test
+// that constructs the value object.
+crate::validated_string! {
+    pub struct ExampleEntitySlug {
+        field: "slug",
+        min_length: 3,
+        max_length: 63,
+        pattern: r"^[a-z0-9]+(-[a-z0-9]+)*$",
+    }
+}
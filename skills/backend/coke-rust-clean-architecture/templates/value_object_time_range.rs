@@ -0,0 +1,83 @@
+// Template: half-open time range `[start, end)`. Adjacent ranges touch but do not
+// overlap, which keeps bucketed queries and retention windows free of double
+// counting.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::DomainError;
+
+// src/domain/value_objects/validated/time_range.rs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "TimeRangeFields", into = "TimeRangeFields")]
+pub struct TimeRange {
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TimeRangeFields {
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+}
+
+impl TimeRange {
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Self, DomainError> {
+        if start >= end {
+            return Err(DomainError::invalid_field("time_range", "validation-out-of-range"));
+        }
+
+        Ok(Self { start, end })
+    }
+
+    pub fn ending_at(end: DateTime<Utc>, duration: Duration) -> Result<Self, DomainError> {
+        Self::new(end - duration, end)
+    }
+
+    pub fn start(&self) -> DateTime<Utc> {
+        self.start
+    }
+
+    pub fn end(&self) -> DateTime<Utc> {
+        self.end
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+
+    pub fn contains(&self, instant: DateTime<Utc>) -> bool {
+        self.start <= instant && instant < self.end
+    }
+
+    pub fn contains_range(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+}
+
+impl TryFrom<TimeRangeFields> for TimeRange {
+    type Error = DomainError;
+
+    fn try_from(fields: TimeRangeFields) -> Result<Self, Self::Error> {
+        Self::new(fields.start, fields.end)
+    }
+}
+
+impl From<TimeRange> for TimeRangeFields {
+    fn from(range: TimeRange) -> Self {
+        Self {
+            start: range.start,
+            end: range.end,
+        }
+    }
+}
+
+impl std::fmt::Display for TimeRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start.to_rfc3339(), self.end.to_rfc3339())
+    }
+}
@@ -0,0 +1,354 @@
+// Template: IANA timezone value object for user or organization preferences.
+// Store it as TEXT next to the owner (`users.timezone`, `orgs.timezone`) and use
+// it wherever day boundaries matter: digest schedules, maintenance windows, and
+// stats buckets. All conversions return UTC instants so repositories and
+// queries never see local times.
+//
+// A user's own timezone wins; a user without one inherits the org's, and an
+// org without one uses UTC. Resolve once per usecase call through
+// `TimezonePreferenceRepository`; never read a timezone from the request.
+
+// migrations/{timestamp}_add_timezone_preferences/up.sql
+//
+// -- NULL means "inherit": users fall back to their org, orgs to UTC.
+// ALTER TABLE users ADD COLUMN timezone TEXT;
+// ALTER TABLE orgs ADD COLUMN timezone TEXT;
+//
+// migrations/{timestamp}_add_timezone_preferences/down.sql
+//
+// ALTER TABLE orgs DROP COLUMN timezone;
+// ALTER TABLE users DROP COLUMN timezone;
+
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, LocalResult, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::TimeRange;
+use crate::domain::DomainError;
+
+// src/domain/value_objects/validated/timezone.rs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Timezone(Tz);
+
+impl Timezone {
+    pub const UTC: Self = Self(Tz::UTC);
+
+    // DST gaps are at most a few hours; a day of minutes is a safe upper bound.
+    const MAX_GAP_MINUTES: i64 = 24 * 60;
+
+    pub fn new(value: String) -> Result<Self, DomainError> {
+        Tz::from_str(value.trim())
+            .map(Self)
+            .map_err(|_| DomainError::invalid_field("timezone", "validation-invalid-format"))
+    }
+
+    // A stored name can disappear from a newer tz database; fall back to UTC
+    // rather than failing every read of the owning row.
+    pub fn from_trusted(value: String) -> Self {
+        Tz::from_str(&value).map(Self).unwrap_or(Self::UTC)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        self.0.name()
+    }
+
+    pub fn to_local(&self, instant: DateTime<Utc>) -> DateTime<Tz> {
+        instant.with_timezone(&self.0)
+    }
+
+    pub fn local_date(&self, instant: DateTime<Utc>) -> NaiveDate {
+        self.to_local(instant).date_naive()
+    }
+
+    // Resolves a wall-clock time. An ambiguous time (clocks set back) takes the
+    // earlier instant; a nonexistent time (clocks set forward) takes the first
+    // instant after the gap.
+    pub fn at_local(&self, date: NaiveDate, time: NaiveTime) -> DateTime<Utc> {
+        let requested = date.and_time(time);
+
+        (0..=Self::MAX_GAP_MINUTES)
+            .find_map(|minutes| {
+                match self.0.from_local_datetime(&(requested + Duration::minutes(minutes))) {
+                    LocalResult::Single(local) | LocalResult::Ambiguous(local, _) => {
+                        Some(local.with_timezone(&Utc))
+                    }
+                    LocalResult::None => None,
+                }
+            })
+            .unwrap_or_else(|| Utc.from_utc_datetime(&requested))
+    }
+
+    pub fn start_of_day(&self, date: NaiveDate) -> DateTime<Utc> {
+        self.at_local(date, NaiveTime::MIN)
+    }
+
+    // The local day as a UTC range. It is 23 or 25 hours long on DST transition
+    // days, so never build day buckets by adding 24 hours.
+    pub fn day_range(&self, date: NaiveDate) -> Result<TimeRange, DomainError> {
+        let next = date
+            .succ_opt()
+            .ok_or_else(|| DomainError::invalid_field("date", "validation-out-of-range"))?;
+
+        TimeRange::new(self.start_of_day(date), self.start_of_day(next))
+    }
+}
+
+impl Default for Timezone {
+    fn default() -> Self {
+        Self::UTC
+    }
+}
+
+impl TryFrom<String> for Timezone {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Timezone> for String {
+    fn from(timezone: Timezone) -> Self {
+        timezone.as_str().to_string()
+    }
+}
+
+impl std::fmt::Display for Timezone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+// src/domain/repositories/timezone_preference_repository.rs
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::repositories::RepoError;
+
+#[async_trait]
+pub trait TimezonePreferenceRepository: Send + Sync {
+    // The user's own setting, else the org's, else `None`.
+    async fn find_effective(&self, user_id: &Uuid) -> Result<Option<Timezone>, RepoError>;
+
+    // `None` clears the setting so the user inherits the org's timezone again.
+    async fn set_for_user(
+        &self,
+        user_id: &Uuid,
+        timezone: Option<Timezone>,
+    ) -> Result<(), RepoError>;
+
+    async fn set_for_org(&self, org_id: &Uuid, timezone: Option<Timezone>)
+        -> Result<(), RepoError>;
+}
+
+// src/infra/db/repositories/timezone_preference_postgres.rs
+use std::sync::Arc;
+
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+
+use crate::infra::db::postgres_connection::PgPool;
+use crate::infra::db::repositories::error_mapping::{map_diesel_error, map_pool_error};
+use crate::infra::db::schema::{orgs, users};
+
+pub struct TimezonePreferencePostgres {
+    pool: Arc<PgPool>,
+}
+
+impl TimezonePreferencePostgres {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TimezonePreferenceRepository for TimezonePreferencePostgres {
+    async fn find_effective(&self, user_id: &Uuid) -> Result<Option<Timezone>, RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        let row = users::table
+            .inner_join(orgs::table)
+            .filter(users::id.eq(user_id))
+            .select((users::timezone, orgs::timezone))
+            .first::<(Option<String>, Option<String>)>(&mut conn)
+            .await
+            .optional()
+            .map_err(|err| map_diesel_error("timezone_preference.find_effective", err))?;
+
+        Ok(row
+            .and_then(|(user_timezone, org_timezone)| user_timezone.or(org_timezone))
+            .map(Timezone::from_trusted))
+    }
+
+    async fn set_for_user(
+        &self,
+        user_id: &Uuid,
+        timezone: Option<Timezone>,
+    ) -> Result<(), RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        let updated = diesel::update(users::table.find(user_id))
+            .set(users::timezone.eq(timezone.map(|timezone| timezone.as_str())))
+            .execute(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("timezone_preference.set_for_user", err))?;
+
+        if updated == 0 {
+            return Err(RepoError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn set_for_org(
+        &self,
+        org_id: &Uuid,
+        timezone: Option<Timezone>,
+    ) -> Result<(), RepoError> {
+        let mut conn = self.pool.get().await.map_err(map_pool_error)?;
+
+        let updated = diesel::update(orgs::table.find(org_id))
+            .set(orgs::timezone.eq(timezone.map(|timezone| timezone.as_str())))
+            .execute(&mut conn)
+            .await
+            .map_err(|err| map_diesel_error("timezone_preference.set_for_org", err))?;
+
+        if updated == 0 {
+            return Err(RepoError::NotFound);
+        }
+        Ok(())
+    }
+}
+
+// src/usecases/preferences/update_timezone.rs
+// The handler passes the raw string; validation happens here so an unknown
+// name becomes a 400 with the `timezone` field key.
+use crate::usecases::UsecaseError;
+
+pub struct UpdateUserTimezoneUseCase {
+    timezone_repo: Arc<dyn TimezonePreferenceRepository>,
+}
+
+impl UpdateUserTimezoneUseCase {
+    pub fn new(timezone_repo: Arc<dyn TimezonePreferenceRepository>) -> Self {
+        Self { timezone_repo }
+    }
+
+    // `None` or an empty string clears the user's own setting.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        timezone: Option<String>,
+    ) -> Result<(), UsecaseError> {
+        let timezone = timezone
+            .filter(|value| !value.trim().is_empty())
+            .map(Timezone::new)
+            .transpose()?;
+
+        self.timezone_repo.set_for_user(&user_id, timezone).await?;
+        Ok(())
+    }
+}
+
+// Usage for stats bucketing, digest scheduling, and maintenance windows in a
+// usecase that holds `timezone_repo` and `clock`:
+//
+// let timezone = self.timezone_repo.find_effective(&user_id).await?.unwrap_or_default();
+// let today = timezone.local_date(self.clock.now());
+//
+// // Stats: one bucket per local day.
+// let range = timezone.day_range(today)?;
+// let count = self.example_repo.count_created_in(owner_id, &range).await?;
+//
+// // Digest: tomorrow at the configured local time, stored as a UTC instant.
+// let send_at = timezone.at_local(today + Duration::days(1), self.digest_time);
+
+// src/domain/value_objects/validated/mod.rs (addition)
+// #[cfg(test)]
+// mod timezone_test;
+
+// src/domain/value_objects/validated/timezone_test.rs
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+
+use super::timezone::Timezone;
+
+fn new_york() -> Timezone {
+    Timezone::new("America/New_York".to_string()).unwrap()
+}
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn time(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+}
+
+fn utc(value: &str) -> DateTime<Utc> {
+    value.parse().unwrap()
+}
+
+#[test]
+fn rejects_unknown_timezone_name() {
+    assert!(Timezone::new("Mars/Olympus_Mons".to_string()).is_err());
+}
+
+#[test]
+fn from_trusted_falls_back_to_utc_for_unknown_name() {
+    assert_eq!(
+        Timezone::from_trusted("Mars/Olympus_Mons".to_string()),
+        Timezone::UTC
+    );
+}
+
+#[test]
+fn day_range_is_24_hours_on_a_regular_day() {
+    let range = new_york().day_range(date(2024, 6, 1)).unwrap();
+
+    assert_eq!(range.start(), utc("2024-06-01T04:00:00Z"));
+    assert_eq!(range.duration(), Duration::hours(24));
+}
+
+#[test]
+fn day_range_is_23_hours_when_clocks_go_forward() {
+    let range = new_york().day_range(date(2024, 3, 10)).unwrap();
+
+    assert_eq!(range.start(), utc("2024-03-10T05:00:00Z"));
+    assert_eq!(range.end(), utc("2024-03-11T04:00:00Z"));
+    assert_eq!(range.duration(), Duration::hours(23));
+}
+
+#[test]
+fn day_range_is_25_hours_when_clocks_go_back() {
+    let range = new_york().day_range(date(2024, 11, 3)).unwrap();
+
+    assert_eq!(range.start(), utc("2024-11-03T04:00:00Z"));
+    assert_eq!(range.end(), utc("2024-11-04T05:00:00Z"));
+    assert_eq!(range.duration(), Duration::hours(25));
+}
+
+#[test]
+fn at_local_takes_earlier_instant_for_ambiguous_time() {
+    // 01:30 happens twice on 2024-11-03: first in EDT (-4), then in EST (-5).
+    let instant = new_york().at_local(date(2024, 11, 3), time(1, 30));
+
+    assert_eq!(instant, utc("2024-11-03T05:30:00Z"));
+}
+
+#[test]
+fn at_local_moves_nonexistent_time_to_end_of_gap() {
+    // 02:00-03:00 does not exist on 2024-03-10; 03:00 EDT is the first instant after it.
+    let instant = new_york().at_local(date(2024, 3, 10), time(2, 30));
+
+    assert_eq!(instant, utc("2024-03-10T07:00:00Z"));
+}
+
+#[test]
+fn local_date_uses_the_zone_not_utc() {
+    let instant = utc("2024-06-02T02:00:00Z");
+
+    assert_eq!(new_york().local_date(instant), date(2024, 6, 1));
+    assert_eq!(Timezone::UTC.local_date(instant), date(2024, 6, 2));
+}
@@ -0,0 +1,163 @@
+// Template destination:
+// src/infra/db/copy_ingest.rs
+// src/infra/db/repositories/example_postgres.rs (create_many dispatch)
+//
+// High-volume ingestion through Postgres `COPY ... FROM STDIN (FORMAT binary)`.
+// diesel-async has no COPY support, so this uses a small tokio-postgres pool as
+// an infra-only escape hatch. Usecases still call `ExampleRepository::create_many`;
+// the repository picks COPY or chunked INSERT.
+//
+// Cargo features (keep COPY optional so builds without it still work):
+//
+// [features]
+// copy-ingest = ["dep:deadpool-postgres", "dep:tokio-postgres"]
+//
+// tokio-postgres needs `with-chrono-0_4` and `with-uuid-1`.
+//
+// Trade-offs to accept before enabling:
+// - COPY is all-or-nothing and has no ON CONFLICT. One duplicate fails the batch.
+//   For idempotent ingestion, COPY into a TEMP staging table, then
+//   `INSERT ... SELECT ... ON CONFLICT DO NOTHING` in the same transaction.
+// - Column order and types below must match the table exactly.
+// - Benchmark with templates/repository-query-benchmark.md; COPY usually wins by
+//   an order of magnitude above a few thousand rows and loses on small batches.
+
+// src/infra/db/copy_ingest.rs
+// Declared as `#[cfg(feature = "copy-ingest")] pub mod copy_ingest;` in infra/db/mod.rs.
+
+use std::pin::pin;
+
+use deadpool_postgres::Pool;
+use tokio_postgres::binary_copy::BinaryCopyInWriter;
+use tokio_postgres::error::SqlState;
+use tokio_postgres::types::Type;
+
+use crate::domain::entities::ExampleEntity;
+use crate::domain::repositories::RepoError;
+use crate::infra::db::repositories::error_mapping::map_pool_error;
+
+pub struct CopyIngest {
+    pool: Pool,
+    // Below this many rows the COPY setup costs more than it saves.
+    min_rows: usize,
+}
+
+impl CopyIngest {
+    pub fn new(pool: Pool, min_rows: usize) -> Self {
+        Self { pool, min_rows }
+    }
+
+    pub fn worth_it(&self, rows: usize) -> bool {
+        rows >= self.min_rows
+    }
+
+    pub async fn copy_example_entities(
+        &self,
+        entities: &[ExampleEntity],
+    ) -> Result<usize, RepoError> {
+        let mut client = self.pool.get().await.map_err(map_pool_error)?;
+        let tx = client.transaction().await.map_err(copy_error)?;
+
+        let sink = tx
+            .copy_in(
+                "COPY example_entities (id, owner_id, column_text, column_url, status, \
+                 created_at, updated_at, deleted_at, version) FROM STDIN (FORMAT binary)",
+            )
+            .await
+            .map_err(copy_error)?;
+
+        let types = [
+            Type::UUID,
+            Type::UUID,
+            Type::TEXT,
+            Type::TEXT,
+            Type::TEXT,
+            Type::TIMESTAMPTZ,
+            Type::TIMESTAMPTZ,
+            Type::TIMESTAMPTZ,
+            Type::INT4,
+        ];
+        let mut writer = pin!(BinaryCopyInWriter::new(sink, &types));
+
+        for entity in entities {
+            writer
+                .as_mut()
+                .write(&[
+                    entity.id().as_uuid(),
+                    entity.owner_id(),
+                    &entity.column_text().as_str(),
+                    &entity.column_url(),
+                    &entity.status().as_str(),
+                    &entity.created_at(),
+                    &entity.updated_at(),
+                    &entity.deleted_at(),
+                    &entity.version(),
+                ])
+                .await
+                .map_err(copy_error)?;
+        }
+
+        let copied = writer.finish().await.map_err(copy_error)?;
+        tx.commit().await.map_err(copy_error)?;
+
+        Ok(copied as usize)
+    }
+}
+
+// Maps the SQLSTATE like `map_diesel_error` does. COPY sends the whole batch as
+// one chunk, so every error is reported as chunk 0.
+fn copy_error(err: tokio_postgres::Error) -> RepoError {
+    const OP: &str = "example_entity.copy_in";
+
+    let message = err
+        .as_db_error()
+        .map_or_else(|| err.to_string(), |db| db.message().to_string());
+    let source = match err.code() {
+        Some(code) if *code == SqlState::UNIQUE_VIOLATION => RepoError::UniqueViolation(message),
+        Some(code) if *code == SqlState::FOREIGN_KEY_VIOLATION => {
+            RepoError::ForeignKeyViolation(message)
+        }
+        Some(code)
+            if *code == SqlState::T_R_SERIALIZATION_FAILURE
+                || *code == SqlState::T_R_DEADLOCK_DETECTED =>
+        {
+            RepoError::Transient {
+                op: OP,
+                source: anyhow::Error::new(err),
+            }
+        }
+        _ => RepoError::Db {
+            op: OP,
+            source: anyhow::Error::new(err),
+        },
+    };
+
+    RepoError::ChunkFailed {
+        op: OP,
+        chunk: 0,
+        source: Box::new(source),
+    }
+}
+
+// src/infra/db/repositories/example_postgres.rs
+// `ExamplePostgres` gains an optional loader set at startup:
+//
+// #[cfg(feature = "copy-ingest")]
+// copy: Option<Arc<CopyIngest>>,
+//
+// and `create_many` dispatches before the chunked INSERT path:
+//
+// #[cfg(feature = "copy-ingest")]
+// if let Some(copy) = self.copy.as_ref().filter(|copy| copy.worth_it(entities.len())) {
+//     match copy.copy_example_entities(entities).await {
+//         Ok(copied) => return Ok(copied),
+//         // The COPY pool is down: nothing was written, so INSERT is safe.
+//         Err(RepoError::ConnectionError(reason)) => {
+//             warn!(reason = %reason, "copy ingest unavailable; falling back to INSERT");
+//         }
+//         Err(err) => return Err(err),
+//     }
+// }
+//
+// Only connection failures fall back. A failed COPY rolled back, and retrying the
+// same rows with INSERT would hit the same constraint violation more slowly.
@@ -0,0 +1,164 @@
+// Template destination:
+// src/infra/db/explain_capture.rs
+// src/handlers/routers/admin_api/explain_capture.rs
+//
+// Dev-only diagnostic. Wire `ExplainCapture::new(..)` into `AppState` only when the
+// deployment config enables dev tools; production keeps `ExplainCapture::disabled()`.
+//
+// `EXPLAIN ANALYZE` executes the statement again. Use `load_observed` for read queries
+// only; never wrap inserts, updates, or deletes.
+
+// src/infra/db/explain_capture.rs
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use diesel::pg::Pg;
+use diesel::query_builder::{AstPass, Query, QueryFragment, QueryId};
+use diesel::sql_types::Text;
+use diesel::QueryResult;
+use diesel_async::methods::LoadQuery;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use tracing::warn;
+
+pub struct ExplainCapture {
+    enabled: AtomicBool,
+    threshold: Duration,
+}
+
+impl ExplainCapture {
+    pub fn new(enabled: bool, threshold: Duration) -> Self {
+        Self {
+            enabled: AtomicBool::new(enabled),
+            threshold,
+        }
+    }
+
+    pub fn disabled() -> Self {
+        Self::new(false, Duration::MAX)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn threshold(&self) -> Duration {
+        self.threshold
+    }
+}
+
+#[derive(Debug, Clone, QueryId)]
+pub(crate) struct ExplainAnalyze<Q>(Q);
+
+impl<Q: QueryFragment<Pg>> QueryFragment<Pg> for ExplainAnalyze<Q> {
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        out.push_sql("EXPLAIN (ANALYZE, BUFFERS) ");
+        self.0.walk_ast(out.reborrow())
+    }
+}
+
+impl<Q> Query for ExplainAnalyze<Q> {
+    type SqlType = Text;
+}
+
+// Runs a read query and, when capture is enabled and the query exceeded the threshold,
+// re-runs it under EXPLAIN (ANALYZE, BUFFERS) with the same binds and logs the plan.
+// Plan capture failures are logged and never change the query result.
+pub(crate) async fn load_observed<'query, Q, U>(
+    capture: &ExplainCapture,
+    op: &'static str,
+    query: Q,
+    conn: &mut AsyncPgConnection,
+) -> QueryResult<Vec<U>>
+where
+    Q: LoadQuery<'query, AsyncPgConnection, U> + QueryFragment<Pg> + QueryId + Clone + Send + 'query,
+    ExplainAnalyze<Q>: LoadQuery<'query, AsyncPgConnection, String>,
+    U: Send,
+{
+    let started = Instant::now();
+    let rows = query.clone().load::<U>(conn).await?;
+    let elapsed = started.elapsed();
+
+    if !capture.is_enabled() || elapsed < capture.threshold() {
+        return Ok(rows);
+    }
+
+    match ExplainAnalyze(query).load::<String>(conn).await {
+        Ok(plan) => warn!(
+            op,
+            elapsed_ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX),
+            plan = %plan.join("\n"),
+            "slow query plan captured"
+        ),
+        Err(err) => warn!(op, error = %err, "slow query plan capture failed"),
+    }
+
+    Ok(rows)
+}
+
+// Usage inside a Diesel repository implementation that holds
+// `capture: Arc<ExplainCapture>` next to `pool: Arc<PgPool>`:
+//
+// let query = example_entities::table
+//     .filter(example_entities::owner_id.eq(*owner_id))
+//     .filter(example_entities::deleted_at.is_null())
+//     .order(example_entities::created_at.desc());
+//
+// let rows = load_observed::<_, ExampleEntityRow>(
+//     &self.capture,
+//     "example_entity.find_by_owner",
+//     query,
+//     &mut conn,
+// )
+// .await
+// .map_err(|err| map_diesel_error("example_entity.find_by_owner", err))?;
+
+// src/handlers/routers/admin_api/explain_capture.rs
+// Admin-only toggle. Register this route only when dev tools are enabled so the
+// endpoint does not exist in production builds of the router.
+use axum::{extract::State, Json};
+use serde::{Deserialize, Serialize};
+
+use crate::handlers::app::state::AppState;
+use crate::handlers::shared::auth::AdminUser;
+use crate::infra::db::explain_capture::ExplainCapture;
+
+#[derive(Debug, Deserialize)]
+pub struct SetExplainCaptureRequest {
+    pub enabled: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExplainCaptureResponse {
+    pub enabled: bool,
+    pub threshold_ms: u64,
+}
+
+impl ExplainCaptureResponse {
+    fn from_capture(capture: &ExplainCapture) -> Self {
+        Self {
+            enabled: capture.is_enabled(),
+            threshold_ms: u64::try_from(capture.threshold().as_millis()).unwrap_or(u64::MAX),
+        }
+    }
+}
+
+pub async fn get_explain_capture(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+) -> Json<ExplainCaptureResponse> {
+    Json(ExplainCaptureResponse::from_capture(&state.explain_capture))
+}
+
+pub async fn set_explain_capture(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+    Json(body): Json<SetExplainCaptureRequest>,
+) -> Json<ExplainCaptureResponse> {
+    state.explain_capture.set_enabled(body.enabled);
+
+    Json(ExplainCaptureResponse::from_capture(&state.explain_capture))
+}
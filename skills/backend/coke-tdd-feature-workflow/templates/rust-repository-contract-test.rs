@@ -0,0 +1,278 @@
+// Template destination:
+// tests/repositories/contract/mod.rs
+// tests/repositories/<entity>_postgres_contract_test.rs
+// tests/repositories/<entity>_in_memory_contract_test.rs
+// tests/repositories/<entity>_sqlite_contract_test.rs (only with the `sqlite` feature)
+//
+// One behavioral suite for every implementation of a repository trait, so the
+// in-memory store used by usecase tests cannot drift from Postgres. Each check
+// is a generic async fn over the trait; `repo_contract_tests!` expands them into
+// `#[tokio::test]`s for one implementation.
+//
+// Keep Postgres-only behavior (Diesel mapping, constraint names, transactions)
+// in rust-repository-integration-test.rs. A check belongs here only when every
+// implementation must pass it.
+//
+// Paths follow coke-rust-clean-architecture; adapt names and imports to the project.
+
+// tests/repositories/contract/mod.rs
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use example_app::domain::entities::ExampleEntity;
+use example_app::domain::repositories::{ExampleRepository, PageRequest, RepoError, Visibility};
+use example_app::domain::value_objects::{ExampleEntityId, ExampleEntityName};
+use uuid::Uuid;
+
+// `$make` is an async fn returning a fresh, empty repository for each test.
+macro_rules! repo_contract_tests {
+    ($make:path) => {
+        $crate::contract::repo_contract_tests!(
+            $make;
+            creates_and_finds_entity,
+            find_by_id_returns_none_for_missing_entity,
+            create_rejects_duplicate_id,
+            update_bumps_version,
+            update_returns_not_found_for_missing_entity,
+            update_returns_stale_version_for_outdated_entity,
+            soft_delete_hides_entity_from_active_count,
+            soft_delete_twice_returns_not_found,
+            restores_soft_deleted_entity,
+            page_respects_limit_and_reports_total,
+            cursor_page_has_next_cursor_only_when_more_rows_exist,
+            purge_respects_limit,
+        );
+    };
+    ($make:path; $($check:ident),+ $(,)?) => {
+        $(
+            #[tokio::test]
+            async fn $check() {
+                let repo = $make().await;
+                $crate::contract::$check(&repo).await;
+            }
+        )+
+    };
+}
+pub(crate) use repo_contract_tests;
+
+// A fixed clock keeps the checks deterministic across runs and implementations.
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap()
+}
+
+fn new_entity(owner_id: Uuid, name: &str) -> ExampleEntity {
+    new_entity_at(owner_id, name, now())
+}
+
+fn new_entity_at(owner_id: Uuid, name: &str, created_at: DateTime<Utc>) -> ExampleEntity {
+    ExampleEntity::new(
+        owner_id,
+        ExampleEntityName::new(name.to_string()).unwrap(),
+        "https://example.com".to_string(),
+        created_at,
+    )
+}
+
+pub async fn creates_and_finds_entity<R: ExampleRepository>(repo: &R) {
+    let entity = new_entity(Uuid::new_v4(), "Valid name");
+
+    repo.create(&entity).await.unwrap();
+    let found = repo.find_by_id(entity.id()).await.unwrap().unwrap();
+
+    assert_eq!(found.id(), entity.id());
+    assert_eq!(found.column_text(), entity.column_text());
+    assert_eq!(found.version(), 1);
+}
+
+pub async fn find_by_id_returns_none_for_missing_entity<R: ExampleRepository>(repo: &R) {
+    let found = repo.find_by_id(&ExampleEntityId::new()).await.unwrap();
+
+    assert!(found.is_none());
+}
+
+pub async fn create_rejects_duplicate_id<R: ExampleRepository>(repo: &R) {
+    let entity = new_entity(Uuid::new_v4(), "Valid name");
+    repo.create(&entity).await.unwrap();
+
+    let result = repo.create(&entity).await;
+
+    assert!(matches!(result, Err(RepoError::UniqueViolation(_))));
+}
+
+pub async fn update_bumps_version<R: ExampleRepository>(repo: &R) {
+    let entity = new_entity(Uuid::new_v4(), "Original name");
+    repo.create(&entity).await.unwrap();
+    let mut stored = repo.find_by_id(entity.id()).await.unwrap().unwrap();
+
+    let name = ExampleEntityName::new("Updated name".to_string()).unwrap();
+    stored.rename(name, now()).unwrap();
+    repo.update(&stored).await.unwrap();
+    let found = repo.find_by_id(entity.id()).await.unwrap().unwrap();
+
+    assert_eq!(found.column_text().as_str(), "Updated name");
+    assert_eq!(found.version(), 2);
+}
+
+pub async fn update_returns_not_found_for_missing_entity<R: ExampleRepository>(repo: &R) {
+    let entity = new_entity(Uuid::new_v4(), "Never stored");
+
+    let result = repo.update(&entity).await;
+
+    assert!(matches!(result, Err(RepoError::NotFound(_))));
+}
+
+pub async fn update_returns_stale_version_for_outdated_entity<R: ExampleRepository>(repo: &R) {
+    let entity = new_entity(Uuid::new_v4(), "Valid name");
+    repo.create(&entity).await.unwrap();
+    let first = repo.find_by_id(entity.id()).await.unwrap().unwrap();
+    let outdated = repo.find_by_id(entity.id()).await.unwrap().unwrap();
+    repo.update(&first).await.unwrap();
+
+    let result = repo.update(&outdated).await;
+
+    assert!(matches!(result, Err(RepoError::StaleVersion(_))));
+}
+
+pub async fn soft_delete_hides_entity_from_active_count<R: ExampleRepository>(repo: &R) {
+    let owner_id = Uuid::new_v4();
+    let kept = new_entity(owner_id, "Kept");
+    let deleted = new_entity(owner_id, "Deleted");
+    repo.create(&kept).await.unwrap();
+    repo.create(&deleted).await.unwrap();
+
+    repo.soft_delete(deleted.id(), now()).await.unwrap();
+
+    let active = repo.count_by_owner(&owner_id, Visibility::ActiveOnly).await.unwrap();
+    let all = repo.count_by_owner(&owner_id, Visibility::IncludeDeleted).await.unwrap();
+    assert_eq!(active, 1);
+    assert_eq!(all, 2);
+}
+
+pub async fn soft_delete_twice_returns_not_found<R: ExampleRepository>(repo: &R) {
+    let entity = new_entity(Uuid::new_v4(), "Valid name");
+    repo.create(&entity).await.unwrap();
+    repo.soft_delete(entity.id(), now()).await.unwrap();
+
+    let result = repo.soft_delete(entity.id(), now()).await;
+
+    assert!(matches!(result, Err(RepoError::NotFound(_))));
+}
+
+pub async fn restores_soft_deleted_entity<R: ExampleRepository>(repo: &R) {
+    let entity = new_entity(Uuid::new_v4(), "Valid name");
+    repo.create(&entity).await.unwrap();
+    repo.soft_delete(entity.id(), now()).await.unwrap();
+
+    repo.restore(entity.id(), now()).await.unwrap();
+    let found = repo.find_by_id(entity.id()).await.unwrap().unwrap();
+
+    assert!(!found.is_deleted());
+    assert_eq!(found.version(), 3);
+}
+
+pub async fn page_respects_limit_and_reports_total<R: ExampleRepository>(repo: &R) {
+    let owner_id = Uuid::new_v4();
+    for name in ["First", "Second", "Third"] {
+        repo.create(&new_entity(owner_id, name)).await.unwrap();
+    }
+
+    let page = repo
+        .find_by_owner_page(&owner_id, Visibility::ActiveOnly, &PageRequest::new(2, 2).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.total, 3);
+}
+
+pub async fn cursor_page_has_next_cursor_only_when_more_rows_exist<R: ExampleRepository>(
+    repo: &R,
+) {
+    let owner_id = Uuid::new_v4();
+    for name in ["First", "Second", "Third"] {
+        repo.create(&new_entity(owner_id, name)).await.unwrap();
+    }
+
+    let first = repo
+        .find_by_owner_after(&owner_id, Visibility::ActiveOnly, None, 2)
+        .await
+        .unwrap();
+    let cursor = first.next_cursor.expect("more rows remain");
+    let last = repo
+        .find_by_owner_after(&owner_id, Visibility::ActiveOnly, Some(&cursor), 2)
+        .await
+        .unwrap();
+
+    assert_eq!(first.items.len(), 2);
+    assert_eq!(last.items.len(), 1);
+    assert!(last.next_cursor.is_none());
+}
+
+pub async fn purge_respects_limit<R: ExampleRepository>(repo: &R) {
+    // `purge_deleted_before` is not scoped to an owner, and the Postgres suite shares one
+    // database. Deleting these rows before every other check's `now()` keeps the cutoff from
+    // reaching rows that parallel checks soft-deleted.
+    let deleted_at = now() - Duration::days(365);
+    let owner_id = Uuid::new_v4();
+    for name in ["First", "Second", "Third"] {
+        let entity = new_entity_at(owner_id, name, deleted_at);
+        repo.create(&entity).await.unwrap();
+        repo.soft_delete(entity.id(), deleted_at).await.unwrap();
+    }
+
+    let purged = repo.purge_deleted_before(deleted_at + Duration::seconds(1), 2).await.unwrap();
+
+    let remaining = repo.count_by_owner(&owner_id, Visibility::IncludeDeleted).await.unwrap();
+    assert_eq!(purged, 2);
+    assert_eq!(remaining, 1);
+}
+
+// tests/repositories/example_postgres_contract_test.rs
+#[path = "../common/mod.rs"]
+mod common;
+#[path = "contract/mod.rs"]
+mod contract;
+
+use common::setup_test_database;
+use contract::repo_contract_tests;
+use example_app::infra::db::repositories::example_entity_postgres::ExamplePostgres;
+
+async fn postgres_repo() -> ExamplePostgres {
+    let db = setup_test_database().await; // project-specific placeholder
+    db.run_migrations().await; // project-specific placeholder
+    ExamplePostgres::new(db.pool())
+}
+
+repo_contract_tests!(postgres_repo);
+
+// tests/repositories/example_in_memory_contract_test.rs
+#[path = "contract/mod.rs"]
+mod contract;
+
+use contract::repo_contract_tests;
+use example_app::infra::memory::example_in_memory::ExampleInMemory;
+
+async fn in_memory_repo() -> ExampleInMemory {
+    ExampleInMemory::new()
+}
+
+repo_contract_tests!(in_memory_repo);
+
+// tests/repositories/example_sqlite_contract_test.rs
+// Starts with `#![cfg(feature = "sqlite")]` so default builds skip it.
+
+#[path = "contract/mod.rs"]
+mod contract;
+
+use std::sync::Arc;
+
+use contract::repo_contract_tests;
+use example_app::infra::db::sqlite::connection::build_sqlite_pool;
+use example_app::infra::db::sqlite::example_sqlite::ExampleSqlite;
+
+async fn sqlite_repo() -> ExampleSqlite {
+    // One connection: every `:memory:` connection is a separate database.
+    let pool = build_sqlite_pool(":memory:", 1).unwrap();
+    run_sqlite_migrations(&pool).await; // project-specific placeholder
+    ExampleSqlite::new(Arc::new(pool))
+}
+
+repo_contract_tests!(sqlite_repo);
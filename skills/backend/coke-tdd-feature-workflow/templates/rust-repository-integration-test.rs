@@ -7,12 +7,15 @@
 #[path = "../common/mod.rs"]
 mod common;
 
+use chrono::Utc;
 use common::setup_test_database;
 use example_app::domain::entities::example_entity::ExampleEntity;
 use example_app::domain::entities::example_entity::ExampleEntityId;
+use example_app::domain::repositories::visibility::Visibility;
 use example_app::domain::value_objects::example_entity_name::ExampleEntityName;
 use example_app::infrastructure::repositories::errors::RepoError;
 use example_app::infrastructure::repositories::example_postgres::ExamplePostgres;
+use uuid::Uuid;
 
 #[tokio::test]
 async fn inserts_and_finds_entity() {
@@ -57,6 +60,54 @@ async fn deletes_existing_entity() {
     assert!(matches!(result, Err(RepoError::NotFound)));
 }
 
+#[tokio::test]
+async fn soft_deleted_entities_do_not_count_toward_owner_total() {
+    let db = setup_test_database().await; // project-specific placeholder
+    db.run_migrations().await; // project-specific placeholder
+    let repo = ExamplePostgres::new(db.pool());
+    let owner_id = Uuid::new_v4();
+    let kept = ExampleEntity::new_owned_by(owner_id, ExampleEntityName::new("Kept").unwrap());
+    let deleted = ExampleEntity::new_owned_by(owner_id, ExampleEntityName::new("Deleted").unwrap());
+    repo.insert(&kept).await.unwrap();
+    repo.insert(&deleted).await.unwrap();
+
+    repo.soft_delete(deleted.id(), Utc::now()).await.unwrap();
+
+    let active = repo.count_by_owner(&owner_id, Visibility::ActiveOnly).await.unwrap();
+    let all = repo.count_by_owner(&owner_id, Visibility::IncludeDeleted).await.unwrap();
+    assert_eq!(active, 1);
+    assert_eq!(all, 2);
+}
+
+#[tokio::test]
+async fn restores_soft_deleted_entity() {
+    let db = setup_test_database().await; // project-specific placeholder
+    db.run_migrations().await; // project-specific placeholder
+    let repo = ExamplePostgres::new(db.pool());
+    let entity = ExampleEntity::new(ExampleEntityName::new("Valid name").unwrap());
+    repo.insert(&entity).await.unwrap();
+    repo.soft_delete(entity.id(), Utc::now()).await.unwrap();
+
+    repo.restore(entity.id(), Utc::now()).await.unwrap();
+    let found = repo.find_by_id(entity.id()).await.unwrap();
+
+    assert!(!found.is_deleted());
+}
+
+#[tokio::test]
+async fn soft_delete_twice_returns_not_found() {
+    let db = setup_test_database().await; // project-specific placeholder
+    db.run_migrations().await; // project-specific placeholder
+    let repo = ExamplePostgres::new(db.pool());
+    let entity = ExampleEntity::new(ExampleEntityName::new("Valid name").unwrap());
+    repo.insert(&entity).await.unwrap();
+    repo.soft_delete(entity.id(), Utc::now()).await.unwrap();
+
+    let result = repo.soft_delete(entity.id(), Utc::now()).await;
+
+    assert!(matches!(result, Err(RepoError::NotFound)));
+}
+
 #[tokio::test]
 async fn maps_row_to_entity() {
     let db = setup_test_database().await; // project-specific placeholder
@@ -8,9 +8,15 @@
 // Use this for usecase orchestration and error semantics.
 // Do not use this template to prove Diesel/Postgres behavior.
 
+use std::sync::Arc;
+
+use chrono::{TimeZone, Utc};
+
 use super::create_example_entity::*;
 use crate::domain::entities::example_entity::ExampleEntity;
 use crate::domain::repositories::example_repository::ExampleRepository;
+use crate::domain::services::Clock;
+use crate::infra::services::clock::FixedClock;
 use crate::repositories::errors::RepoError;
 use crate::usecases::errors::UsecaseError;
 
@@ -32,10 +38,15 @@ impl ExampleRepository for FakeExampleRepository {
     }
 }
 
+// Every test runs at the same instant, so timestamps in outputs are stable.
+fn fixed_clock() -> Arc<dyn Clock> {
+    Arc::new(FixedClock::new(Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap()))
+}
+
 #[tokio::test]
 async fn creates_entity_when_input_is_valid() {
     let repo = FakeExampleRepository::default();
-    let usecase = CreateExampleEntityUseCase::new(repo);
+    let usecase = CreateExampleEntityUseCase::new(repo, fixed_clock());
     let input = CreateExampleEntityInput {
         name: "Valid name".to_string(),
     };
@@ -48,14 +59,14 @@ async fn creates_entity_when_input_is_valid() {
 #[tokio::test]
 async fn returns_validation_error_when_name_is_empty() {
     let repo = FakeExampleRepository::default();
-    let usecase = CreateExampleEntityUseCase::new(repo);
+    let usecase = CreateExampleEntityUseCase::new(repo, fixed_clock());
     let input = CreateExampleEntityInput {
         name: "".to_string(),
     };
 
     let result = usecase.execute(input).await;
 
-    assert!(matches!(result, Err(UsecaseError::Validation(_))));
+    assert!(matches!(result, Err(UsecaseError::InvalidField { .. })));
 }
 
 #[tokio::test]
@@ -64,7 +75,7 @@ async fn returns_conflict_when_repository_reports_duplicate() {
         next_error: Some(RepoError::Conflict("duplicate".into())),
         ..Default::default()
     };
-    let usecase = CreateExampleEntityUseCase::new(repo);
+    let usecase = CreateExampleEntityUseCase::new(repo, fixed_clock());
     let input = CreateExampleEntityInput {
         name: "Valid name".to_string(),
     };
@@ -80,7 +91,7 @@ async fn maps_repo_error_to_usecase_error() {
         next_error: Some(RepoError::Unavailable("database unavailable".into())),
         ..Default::default()
     };
-    let usecase = CreateExampleEntityUseCase::new(repo);
+    let usecase = CreateExampleEntityUseCase::new(repo, fixed_clock());
     let input = CreateExampleEntityInput {
         name: "Valid name".to_string(),
     };